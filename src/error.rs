@@ -6,6 +6,10 @@ pub enum Error {
     Format(String),
     #[error("An Error occured from a set request")]
     Status,
+    #[error("Timed out waiting for the telescope")]
+    Timeout,
+    #[error("The command is not supported by this telescope")]
+    Unsupported,
     #[error(transparent)]
     IO(#[from] std::io::Error),
 }
@@ -27,11 +31,23 @@ impl<I> From<nom::Err<nom::error::Error<I>>> for Error {
 }
 
 impl Error {
+    /// Parses the single byte reply of a set request, where `0` reports the request was rejected.
     pub fn parse(input: &[u8]) -> Result<(), Self> {
         let (_, bytes) = take(1usize)(input)?;
-        if bytes[0] == 0 {
+        if matches!(bytes[0], 0 | b'0') {
             return Err(Error::Status);
         }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+
+    #[test]
+    fn test_parse_rejects_ascii_zero() {
+        assert!(Error::parse(b"1").is_ok());
+        assert!(matches!(Error::parse(b"0"), Err(Error::Status)));
+    }
+}
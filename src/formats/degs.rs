@@ -17,7 +17,7 @@ impl Degrees {
     }
 
     pub fn from_bytes(input: &[u8]) -> IResult<&[u8], Self> {
-        map_res(take(2usize), |value| {
+        map_res(take(3usize), |value| {
             let v = str::from_utf8(value)?;
             let v = v
                 .parse::<u16>()
@@ -64,7 +64,7 @@ impl SignedDegrees {
 
 impl Display for SignedDegrees {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:+02}", self.0)
+        write!(f, "{:+03}", self.0)
     }
 }
 
@@ -69,6 +69,6 @@ impl SignedHours {
 
 impl Display for SignedHours {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:+02}", self.0)
+        write!(f, "{:+03}", self.0)
     }
 }
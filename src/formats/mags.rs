@@ -0,0 +1,44 @@
+use core::str;
+use std::fmt::Display;
+
+use nom::{bytes::complete::take, combinator::map_res, IResult};
+
+use super::FormatError;
+
+/// Represents `sMM.M` from `-5.5 to 20.0`
+#[derive(Debug, PartialEq)]
+pub struct Magnitude(f32);
+
+impl Magnitude {
+    #[inline]
+    fn is_invalid(val: f32) -> bool {
+        !(-5.5..=20.0).contains(&val)
+    }
+
+    pub fn new(val: f32) -> Option<Self> {
+        if Self::is_invalid(val) {
+            return None;
+        }
+        Some(Magnitude(val))
+    }
+
+    pub fn from_bytes(input: &[u8]) -> IResult<&[u8], Self> {
+        map_res(take(5usize), |value| {
+            let v = str::from_utf8(value)?;
+            let v = v
+                .trim()
+                .parse::<f32>()
+                .map_err(|err| FormatError::Num(Box::new(err)))?;
+            if Self::is_invalid(v) {
+                return Err(FormatError::BoundaryReached);
+            }
+            Ok(Magnitude(v))
+        })(input)
+    }
+}
+
+impl Display for Magnitude {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:+05.1}", self.0)
+    }
+}
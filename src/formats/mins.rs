@@ -3,7 +3,7 @@ use std::fmt::Display;
 
 use nom::{bytes::complete::take, combinator::map_res, IResult};
 
-use super::FormatError;
+use super::{secs::Seconds, FormatError};
 
 /// Represents `MM` from `00-59`
 #[derive(Debug)]
@@ -72,6 +72,14 @@ impl Tenths {
             Ok(Tenths(v))
         })(input)
     }
+
+    /// Converts tenths of a minute into whole seconds.
+    pub const fn to_seconds(&self) -> Seconds {
+        match Seconds::new(self.0 * 6) {
+            Some(seconds) => seconds,
+            None => unreachable!(),
+        }
+    }
 }
 
 impl Display for Tenths {
@@ -79,3 +87,35 @@ impl Display for Tenths {
         write!(f, "{:01}", self.0)
     }
 }
+
+/// Represents `NNN` arc minutes from `000-999`
+#[derive(Debug, PartialEq)]
+pub struct ArcMinutes(u16);
+
+impl ArcMinutes {
+    pub const fn new(val: u16) -> Option<Self> {
+        if val > 999 {
+            return None;
+        }
+        Some(ArcMinutes(val))
+    }
+
+    pub fn from_bytes(input: &[u8]) -> IResult<&[u8], Self> {
+        map_res(take(3usize), |value| {
+            let v = str::from_utf8(value)?;
+            let v = v
+                .parse::<u16>()
+                .map_err(|err| FormatError::Num(Box::new(err)))?;
+            if v > 999 {
+                return Err(FormatError::BoundaryReached);
+            }
+            Ok(ArcMinutes(v))
+        })(input)
+    }
+}
+
+impl Display for ArcMinutes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:03}", self.0)
+    }
+}
@@ -1,6 +1,7 @@
 pub mod days;
 pub mod degs;
 pub mod hrs;
+pub mod mags;
 pub mod mins;
 pub mod months;
 pub mod secs;
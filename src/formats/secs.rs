@@ -3,7 +3,7 @@ use std::fmt::Display;
 
 use nom::{bytes::complete::take, combinator::map_res, IResult};
 
-use super::FormatError;
+use super::{mins::Tenths, FormatError};
 
 pub struct Seconds(u8);
 
@@ -27,6 +27,14 @@ impl Seconds {
             Ok(Seconds(v))
         })(input)
     }
+
+    /// Converts seconds into tenths of a minute, rounding down.
+    pub const fn to_tenths(&self) -> Tenths {
+        match Tenths::new(self.0 / 6) {
+            Some(tenths) => tenths,
+            None => unreachable!(),
+        }
+    }
 }
 
 impl Display for Seconds {
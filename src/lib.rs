@@ -1,26 +1,53 @@
-use std::ops::{Deref, DerefMut};
+use std::{
+    ops::{Deref, DerefMut},
+    time::{Duration, Instant, SystemTime},
+};
 
 use error::Error;
 use formats::hrs::SignedHours;
 use requests::{
-    alt::{Altitude, GET_ALTITUDE, GET_ALTITUDE_SIZE},
-    asc::{
-        Ascension, GET_OBJECT_RIGHT_ASCENSION, GET_OBJECT_RIGHT_ASCENSION_SIZE,
-        GET_RIGHT_ASCENSION, GET_RIGHT_ASCENSION_SIZE,
-    },
-    azim::{Azimuth, GET_AZMITH, GET_AZMITH_SIZE},
+    alt::{Altitude, GET_ALTITUDE},
+    asc::{Ascension, GET_OBJECT_RIGHT_ASCENSION, GET_RIGHT_ASCENSION},
+    azim::{Azimuth, GET_AZMITH},
+    backlash::BacklashSettings,
     cal::{Date, GET_DATE, GET_DATE_SIZE},
-    decl::{Declination, GET_DECLANATION, GET_DECLANATION_SIZE, GET_OBJECT_DECLANATION},
+    clock::Clock,
+    decl::{Declination, GET_DECLANATION, GET_OBJECT_DECLANATION},
+    distance::{DistanceBars, GET_DISTANCE_BARS},
+    find::{
+        FindParameters, ObjectTypes, GET_BRIGHTER_LIMIT, GET_FAINTER_LIMIT, GET_LARGER_LIMIT,
+        GET_MAGNITUDE_LIMIT_SIZE, GET_MINIMUM_QUALITY, GET_MINIMUM_QUALITY_SIZE, GET_OBJECT_TYPES,
+        GET_OBJECT_TYPES_SIZE, GET_SIZE_LIMIT_SIZE, GET_SMALLER_LIMIT, STEP_MINIMUM_QUALITY,
+    },
+    firmware::{
+        FirmwareInfo, Product, GET_FIRMWARE_DATE, GET_FIRMWARE_TIME, GET_FIRMWARE_VERSION,
+        GET_PRODUCT_NAME,
+    },
+    focus::{FocusDirection, FocusSpeed},
+    gps::{NmeaSentence, GET_NMEA_SENTENCE, GPS_OFF, GPS_ON, UPDATE_TIME_FROM_GPS},
+    handbox::{decode_display, HandboxKey, GET_DISPLAY},
+    home::{HomeStatus, GET_HOME_STATUS, GET_HOME_STATUS_SIZE},
     latlng::{
         Latitude, Longitude, GET_LATITUDE, GET_LATITUDE_SIZE, GET_LONGITUDE, GET_LONGITUDE_SIZE,
     },
-    site::Site,
+    library::{
+        DeepSkyCatalog, ObjectInfo, ObjectNumber, ObjectQuality, StarCatalog, GET_OBJECT_INFO,
+    },
+    limit::{
+        ElevationLimit, HorizonLimit, GET_HIGHER_LIMIT, GET_HIGHER_LIMIT_SIZE, GET_LOWER_LIMIT,
+        GET_LOWER_LIMIT_SIZE,
+    },
+    pointing::{PointingPrecision, SlewArrival, TOGGLE_POINTING_PRECISION},
+    reticle::{BrightnessStep, FlashMode},
+    site::{Site, SiteInfo},
     time::{
         Time, GET_GREENWICH_MEAN_TIME, GET_GREENWICH_MEAN_TIME_SIZE, GET_LOCAL_12_HOUR_TIME,
         GET_LOCAL_24_HOUR_TIME, GET_SIDREAL_TIME, GET_TIME_SIZE,
     },
-    AlignmentStatus, Direction, MotionRate, SlewStatus, GET_ALIGNMENT_STATUS,
-    GET_ALIGNMENT_STATUS_SIZE, SYNC,
+    tracking::{TrackingRate, GET_TRACKING_RATE, GET_TRACKING_RATE_SIZE},
+    AlignmentStatus, Axis, Direction, MaxSlewRate, MotionRate, Precision, SlewStatus, SyncResult,
+    GET_ALIGNMENT_STATUS, GET_ALIGNMENT_STATUS_SIZE, GET_STATUS, MAX_AXIS_RATE,
+    MAX_PULSE_GUIDE_MILLIS, SYNC,
 };
 
 pub mod error;
@@ -37,31 +64,212 @@ pub trait Stream: tokio::io::AsyncReadExt + tokio::io::AsyncWriteExt + std::mark
 #[cfg(feature = "tokio")]
 impl<T: tokio::io::AsyncReadExt + tokio::io::AsyncWriteExt + std::marker::Unpin> Stream for T {}
 
+/// How long to wait for a response that some telescopes never send, such as the product name on a classic LX200.
+///
+/// Without the `tokio` feature this is the read timeout [Client::open] and [Client::connect] set on the stream,
+/// so other streams need a read timeout of their own.
+pub const RESPONSE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Waits for the duration, blocking the current thread.
+#[cfg(not(feature = "tokio"))]
+fn delay(duration: Duration) {
+    std::thread::sleep(duration)
+}
+
+/// Waits for the duration without blocking the runtime.
+#[cfg(feature = "tokio")]
+async fn delay(duration: Duration) {
+    tokio::time::sleep(duration).await
+}
+
 /// Command set derived from [LX200 Spec](https://www.skymtn.com/mapug-astronomy/ragreiner/LX200Commands.html)
 pub struct Client<T> {
     stream: T,
+    precision: Precision,
+    pointing: Option<PointingPrecision>,
+    intermediate_stop: bool,
+    product: Option<Product>,
+    backlash: BacklashSettings,
 }
 
 impl<T: Stream> Client<T> {
+    /// Creates a client assuming the telescope is in [Precision::Low], see [Client::detect_precision].
     pub fn new(stream: T) -> Self {
-        Client { stream }
+        Client {
+            stream,
+            precision: Precision::Low,
+            pointing: None,
+            intermediate_stop: false,
+            product: None,
+            backlash: BacklashSettings::default(),
+        }
     }
 
     pub fn get_mut(&mut self) -> &mut T {
         &mut self.stream
     }
+
+    /// Reads a variable length response up to and including the `#` terminator.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    async fn read_response(&mut self) -> Result<Vec<u8>, Error> {
+        let mut response = Vec::new();
+        let mut byte = [0u8; 1];
+        loop {
+            self.stream.read_exact(&mut byte).await?;
+            response.push(byte[0]);
+            if byte[0] == b'#' {
+                return Ok(response);
+            }
+        }
+    }
+
+    /// Reads a variable length response, yielding [None] if the stream times out before it arrives.
+    #[cfg(not(feature = "tokio"))]
+    fn read_optional_response(&mut self) -> Result<Option<Vec<u8>>, Error> {
+        match self.read_response() {
+            Ok(response) => Ok(Some(response)),
+            Err(Error::IO(err))
+                if matches!(
+                    err.kind(),
+                    std::io::ErrorKind::TimedOut | std::io::ErrorKind::WouldBlock
+                ) =>
+            {
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Reads a variable length response, yielding [None] if it does not arrive within [RESPONSE_TIMEOUT].
+    #[cfg(feature = "tokio")]
+    async fn read_optional_response(&mut self) -> Result<Option<Vec<u8>>, Error> {
+        match tokio::time::timeout(RESPONSE_TIMEOUT, self.read_response()).await {
+            Ok(response) => response.map(Some),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// Reads a single byte status that may take longer than the read timeout of the stream to arrive,
+    /// failing with [Error::Timeout] if it does not arrive within `timeout`.
+    #[cfg(not(feature = "tokio"))]
+    fn read_status_within(&mut self, timeout: Duration) -> Result<[u8; 1], Error> {
+        let start = Instant::now();
+        let mut response = [0u8; 1];
+        loop {
+            match self.stream.read_exact(&mut response) {
+                Ok(()) => return Ok(response),
+                Err(err)
+                    if matches!(
+                        err.kind(),
+                        std::io::ErrorKind::TimedOut | std::io::ErrorKind::WouldBlock
+                    ) =>
+                {
+                    if start.elapsed() >= timeout {
+                        return Err(Error::Timeout);
+                    }
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
+
+    /// Reads a single byte status, failing with [Error::Timeout] if it does not arrive within `timeout`.
+    #[cfg(feature = "tokio")]
+    async fn read_status_within(&mut self, timeout: Duration) -> Result<[u8; 1], Error> {
+        let mut response = [0u8; 1];
+        match tokio::time::timeout(timeout, self.stream.read_exact(&mut response)).await {
+            Ok(result) => result.map(|_| response).map_err(Error::from),
+            Err(_) => Err(Error::Timeout),
+        }
+    }
 }
 
 impl<T: Stream + 'static> Client<T> {
     pub fn into_boxed(self) -> BoxedClient {
         BoxedClient(Client {
             stream: Box::new(self.stream),
+            precision: self.precision,
+            pointing: self.pointing,
+            intermediate_stop: self.intermediate_stop,
+            product: self.product,
+            backlash: self.backlash,
         })
     }
 }
 
 /// General Telescope Information
 impl<T: Stream> Client<T> {
+    /// The coordinate precision the telescope is assumed to be in.
+    pub fn precision(&self) -> &Precision {
+        &self.precision
+    }
+
+    /// The product family found by [Client::identify].
+    ///
+    /// Commands documented as only supported by some products fail with [Error::Unsupported]
+    /// once this is another known product. Unidentified telescopes and [Product::Other] are not restricted.
+    pub fn product(&self) -> Option<&Product> {
+        self.product.as_ref()
+    }
+
+    fn require_product(&self, supported: &[Product]) -> Result<(), Error> {
+        match &self.product {
+            None | Some(Product::Other(_)) => Ok(()),
+            Some(product) if supported.contains(product) => Ok(()),
+            Some(_) => Err(Error::Unsupported),
+        }
+    }
+
+    /// Identifies the product and firmware of the telescope and detects the coordinate precision it is in.
+    ///
+    /// A telescope that does not answer the product name within [RESPONSE_TIMEOUT] is a [Product::Lx200Classic].
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn identify(&mut self) -> Result<FirmwareInfo, Error> {
+        self.stream.write_all(GET_PRODUCT_NAME).await?;
+        let info = match self.read_optional_response().await? {
+            None => FirmwareInfo {
+                product: Product::Lx200Classic,
+                product_name: None,
+                version: None,
+                date: None,
+                time: None,
+            },
+            Some(response) => {
+                let product_name = FirmwareInfo::parse_string(&response)?;
+                self.stream.write_all(GET_FIRMWARE_VERSION).await?;
+                let version = FirmwareInfo::parse_string(&self.read_response().await?)?;
+                self.stream.write_all(GET_FIRMWARE_DATE).await?;
+                let date = FirmwareInfo::parse_string(&self.read_response().await?)?;
+                self.stream.write_all(GET_FIRMWARE_TIME).await?;
+                let time = Time::parse(&self.read_response().await?)?;
+                FirmwareInfo {
+                    product: Product::from_name(&product_name),
+                    product_name: Some(product_name),
+                    version: Some(version),
+                    date: Some(date),
+                    time: Some(time),
+                }
+            }
+        };
+        self.product = Some(info.product.clone());
+        self.detect_precision().await?;
+        Ok(info)
+    }
+
+    /// Toggles the telescope between low and high precision coordinates, then detects the precision it settled on.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn toggle_precision(&mut self) -> Result<Precision, Error> {
+        self.stream.write_all(b":U#").await?;
+        self.detect_precision().await
+    }
+
+    /// Detects the coordinate precision of the telescope from the format of the current Right Ascension.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn detect_precision(&mut self) -> Result<Precision, Error> {
+        self.precision = self.get_right_ascension().await?.precision();
+        Ok(self.precision.clone())
+    }
+
     /// Gets alignment status.
     #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
     pub async fn alignment_status(&mut self) -> Result<AlignmentStatus, Error> {
@@ -71,12 +279,18 @@ impl<T: Stream> Client<T> {
         AlignmentStatus::parse(&response)
     }
 
+    /// Sets the alignment mode.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn set_alignment_mode(&mut self, mode: &AlignmentStatus) -> Result<(), Error> {
+        self.stream.write_all(mode.set_request()?).await?;
+        Ok(())
+    }
+
     /// Gets the current Right Ascension.
     #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
     pub async fn get_right_ascension(&mut self) -> Result<Ascension, Error> {
         self.stream.write_all(GET_RIGHT_ASCENSION).await?;
-        let mut response = [0u8; GET_RIGHT_ASCENSION_SIZE];
-        self.stream.read_exact(&mut response).await?;
+        let response = self.read_response().await?;
         Ascension::parse(&response)
     }
 
@@ -84,8 +298,7 @@ impl<T: Stream> Client<T> {
     #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
     pub async fn get_declination(&mut self) -> Result<Declination, Error> {
         self.stream.write_all(GET_DECLANATION).await?;
-        let mut response = [0u8; GET_DECLANATION_SIZE];
-        self.stream.read_exact(&mut response).await?;
+        let response = self.read_response().await?;
         Declination::parse(&response)
     }
 
@@ -93,18 +306,14 @@ impl<T: Stream> Client<T> {
     #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
     pub async fn get_altitude(&mut self) -> Result<Altitude, Error> {
         self.stream.write_all(GET_ALTITUDE).await?;
-        let mut response = [0u8; GET_ALTITUDE_SIZE];
-        self.stream.read_exact(&mut response).await?;
-        Altitude::parse(&response)
+        Altitude::parse(&self.read_response().await?)
     }
 
     /// Gets the current azimuth.
     #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
     pub async fn get_azmith(&mut self) -> Result<Azimuth, Error> {
         self.stream.write_all(GET_AZMITH).await?;
-        let mut response = [0u8; GET_AZMITH_SIZE];
-        self.stream.read_exact(&mut response).await?;
-        Azimuth::parse(&response)
+        Azimuth::parse(&self.read_response().await?)
     }
 
     /// Gets the current sidereal time.
@@ -257,6 +466,38 @@ impl<T: Stream> Client<T> {
         Error::parse(&response)
     }
 
+    /// Sets the offset from Greenwich Mean Time, local time and calendar date from the clock.
+    ///
+    /// Once [Client::identify] found an Autostar or LX200GPS, daylight saving is set with `:SH`.
+    /// Otherwise it is folded into the offset from Greenwich Mean Time.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn set_clock(&mut self, clock: &Clock) -> Result<(), Error> {
+        let supports_daylight_saving =
+            matches!(self.product, Some(Product::Autostar | Product::Lx200Gps));
+        self.set_gmt_offset(&clock.gmt_offset(!supports_daylight_saving)?)
+            .await?;
+        if supports_daylight_saving {
+            self.stream
+                .write_all(format!(":SH{}#", clock.daylight_saving as u8).as_bytes())
+                .await?;
+        }
+        self.set_local_time(&clock.time).await?;
+        self.set_calendar_date(&clock.date).await
+    }
+
+    /// Sets the telescope clock from an instant and the standard offset from UTC in hours, e.g. `-5` for EST.
+    /// See [Client::set_clock].
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn sync_clock(
+        &mut self,
+        now: SystemTime,
+        utc_offset: i8,
+        daylight_saving: bool,
+    ) -> Result<(), Error> {
+        let clock = Clock::new(now, utc_offset, daylight_saving)?;
+        self.set_clock(&clock).await
+    }
+
     /// Sets the current site number.
     #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
     pub async fn set_site(&mut self, site: &Site) -> Result<(), Error> {
@@ -265,6 +506,40 @@ impl<T: Stream> Client<T> {
             .await?;
         Ok(())
     }
+
+    /// Gets the name of the site.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn get_site_name(&mut self, site: &Site) -> Result<String, Error> {
+        self.stream.write_all(site.get_name_request()).await?;
+        let response = self.read_response().await?;
+        Site::parse_name(&response)
+    }
+
+    /// Sets the name of the site.
+    ///
+    /// **NOTE**: Names longer than [requests::site::SITE_NAME_MAX_SIZE] are rejected before sending.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn set_site_name(&mut self, site: &Site, name: &str) -> Result<(), Error> {
+        let request = site.set_name_request(name)?;
+        self.stream.write_all(&request).await?;
+        let mut response = [0u8; 1];
+        self.stream.read_exact(&mut response).await?;
+        Error::parse(&response)
+    }
+
+    /// Selects the site and gets its name, latitude, longitude and offset from Greenwich Mean Time.
+    ///
+    /// **NOTE**: The site remains selected afterwards.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn get_site_info(&mut self, site: &Site) -> Result<SiteInfo, Error> {
+        self.set_site(site).await?;
+        Ok(SiteInfo {
+            name: self.get_site_name(site).await?,
+            latitude: self.get_latitude().await?,
+            longitude: self.get_longitude().await?,
+            gmt_offset: self.get_gmt().await?,
+        })
+    }
 }
 
 /// Telescope Motion
@@ -282,12 +557,123 @@ impl<T: Stream> Client<T> {
     #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
     pub async fn slew(&mut self) -> Result<SlewStatus, Error> {
         self.stream.write_all(b":MS#").await?;
+        let mut response = vec![0u8; 1];
+        self.stream.read_exact(&mut response).await?;
+        if SlewStatus::has_message(response[0]) {
+            let message = self.read_response().await?;
+            response.extend(message);
+        }
+        let status = SlewStatus::parse(&response)?;
+        self.intermediate_stop = matches!(status, SlewStatus::CanComplete)
+            && matches!(self.pointing, Some(PointingPrecision::High));
+        Ok(status)
+    }
+
+    /// Slews telescope to the target altitude and azimuth.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn slew_altaz(&mut self) -> Result<(), Error> {
+        self.stream.write_all(b":MA#").await?;
         let mut response = [0u8; 1];
         self.stream.read_exact(&mut response).await?;
-        //TODO: If 1 or 2 is returned, a string containing an appropriate message is also returned.
-        //Handle this message???
-        SlewStatus::parse(&response)
+        // 0 is returned if there was no fault, 1 otherwise.
+        match response[0] {
+            b'0' => Ok(()),
+            _ => Err(Error::Status),
+        }
+    }
+
+    /// Gets the distance bars to the current object, which are only present while slewing.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn distance_bars(&mut self) -> Result<DistanceBars, Error> {
+        self.stream.write_all(GET_DISTANCE_BARS).await?;
+        let response = self.read_response().await?;
+        DistanceBars::parse(&response)
+    }
+
+    /// Polls [Client::distance_bars] every `poll_interval` until the slew finishes or `timeout` elapses.
+    ///
+    /// The first slew started by [Client::slew] while high precision pointing is known to be enabled,
+    /// see [Client::pointing_precision], stops at the intermediate star rather than the object.
+    /// Only that stop is reported as [SlewArrival::IntermediateStar], the leg continued from the handbox is not.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn wait_for_slew(
+        &mut self,
+        timeout: Duration,
+        poll_interval: Duration,
+    ) -> Result<SlewArrival, Error> {
+        let start = Instant::now();
+        loop {
+            if !self.distance_bars().await?.is_slewing() {
+                if std::mem::take(&mut self.intermediate_stop) {
+                    return Ok(SlewArrival::IntermediateStar);
+                }
+                return Ok(SlewArrival::Object);
+            }
+            if start.elapsed() >= timeout {
+                return Err(Error::Timeout);
+            }
+            delay(poll_interval).await;
+        }
+    }
+
+    /// The pointing mode last reported by the telescope through this client.
+    ///
+    /// **NOTE**: This is [None] until [Client::toggle_pointing_precision] is called,
+    /// and goes stale if the mode is toggled from the keypad.
+    pub fn pointing_precision(&self) -> Option<&PointingPrecision> {
+        self.pointing.as_ref()
+    }
+
+    /// Toggles high precision pointing and yields the new setting.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn toggle_pointing_precision(&mut self) -> Result<PointingPrecision, Error> {
+        self.stream.write_all(TOGGLE_POINTING_PRECISION).await?;
+        let mut first = [0u8; 1];
+        self.stream.read_exact(&mut first).await?;
+        let mut response = vec![0u8; PointingPrecision::response_size(first[0])?];
+        response[0] = first[0];
+        self.stream.read_exact(&mut response[1..]).await?;
+        let pointing = PointingPrecision::parse(&response)?;
+        self.pointing = Some(pointing.clone());
+        Ok(pointing)
     }
+
+    /// Moves in the specified direction at the guide rate for the duration, timed by the telescope.
+    ///
+    /// **NOTE**: The duration must be between 1 and [MAX_PULSE_GUIDE_MILLIS] milliseconds.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn pulse_guide(
+        &mut self,
+        direction: &Direction,
+        duration: Duration,
+    ) -> Result<(), Error> {
+        let millis = duration.as_millis();
+        if !(1..=MAX_PULSE_GUIDE_MILLIS).contains(&millis) {
+            return Err(Error::Format(format!(
+                "pulse of {}ms is outside 1-{}ms",
+                millis, MAX_PULSE_GUIDE_MILLIS
+            )));
+        }
+        self.stream
+            .write_all(format!(":Mg{}{:04}#", direction, millis).as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    /// Checks whether a pulse guide is in progress.
+    ///
+    /// **NOTE**: Meade firmware has no such query, so this uses the `:GU#` status string of OnStep compatible
+    /// telescopes. It fails with [Error::Unsupported] unless [Client::identify] found [Product::OnStep].
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn is_guiding(&mut self) -> Result<bool, Error> {
+        if self.product != Some(Product::OnStep) {
+            return Err(Error::Unsupported);
+        }
+        self.stream.write_all(GET_STATUS).await?;
+        let response = self.read_response().await?;
+        Ok(response.contains(&b'G'))
+    }
+
     /// Stops motion in the specified direction. Also stops the telescope if a slew to object is in progress.
     #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
     pub async fn stop_motion(&mut self, direction: &Direction) -> Result<(), Error> {
@@ -304,6 +690,74 @@ impl<T: Stream> Client<T> {
         Ok(())
     }
 
+    /// Sets a custom rate in degrees per second for motion along the axis, replacing the [MotionRate].
+    ///
+    /// **NOTE**: Only supported by Autostar and LX200GPS telescopes.
+    /// The rate must be between 0 and [MAX_AXIS_RATE] and is sent rounded to a tenth as `DD.D`.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn set_axis_rate(&mut self, axis: &Axis, rate: f64) -> Result<(), Error> {
+        self.require_product(&[Product::Autostar, Product::Lx200Gps])?;
+        if !(0.0..=MAX_AXIS_RATE).contains(&rate) {
+            return Err(Error::Format(format!(
+                "axis rate {} is outside 0-{} degrees per second",
+                rate, MAX_AXIS_RATE
+            )));
+        }
+        self.stream
+            .write_all(format!(":R{}{:04.1}#", axis, rate).as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    /// Sets the maximum slew rate.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn set_max_slew_rate(&mut self, rate: &MaxSlewRate) -> Result<(), Error> {
+        self.stream
+            .write_all(format!(":Sw{}#", rate).as_bytes())
+            .await?;
+        let mut response = [0u8; 1];
+        self.stream.read_exact(&mut response).await?;
+        Error::parse(&response)
+    }
+
+    /// Gets the 'higher' limit, the minimum elevation to slew to. Slews to objects below it report [SlewStatus::BelowLimit].
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn get_higher_limit(&mut self) -> Result<HorizonLimit, Error> {
+        self.stream.write_all(GET_HIGHER_LIMIT).await?;
+        let mut response = [0u8; GET_HIGHER_LIMIT_SIZE];
+        self.stream.read_exact(&mut response).await?;
+        HorizonLimit::parse(&response)
+    }
+
+    /// Sets the 'higher' limit, the minimum elevation to slew to.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn set_higher_limit(&mut self, limit: &HorizonLimit) -> Result<(), Error> {
+        self.stream.write_all(&limit.set_request()).await?;
+        let mut response = [0u8; 1];
+        self.stream.read_exact(&mut response).await?;
+        Error::parse(&response)
+    }
+
+    /// Gets the 'lower' limit, which despite its name is the maximum (overhead) elevation to slew to.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn get_lower_limit(&mut self) -> Result<ElevationLimit, Error> {
+        self.stream.write_all(GET_LOWER_LIMIT).await?;
+        let mut response = [0u8; GET_LOWER_LIMIT_SIZE];
+        self.stream.read_exact(&mut response).await?;
+        ElevationLimit::parse(&response)
+    }
+
+    /// Sets the 'lower' limit, which despite its name is the maximum (overhead) elevation to slew to.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn set_lower_limit(&mut self, limit: &ElevationLimit) -> Result<(), Error> {
+        self.stream
+            .write_all(format!(":So {}#", limit).as_bytes())
+            .await?;
+        let mut response = [0u8; 1];
+        self.stream.read_exact(&mut response).await?;
+        Error::parse(&response)
+    }
+
     /// Sets the motion rate.
     #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
     pub async fn set_motion_rate(&mut self, motion_rate: &MotionRate) -> Result<(), Error> {
@@ -314,14 +768,313 @@ impl<T: Stream> Client<T> {
     }
 }
 
+/// Tracking
+impl<T: Stream> Client<T> {
+    /// Gets the tracking frequency.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn get_tracking_rate(&mut self) -> Result<TrackingRate, Error> {
+        self.stream.write_all(GET_TRACKING_RATE).await?;
+        let mut response = [0u8; GET_TRACKING_RATE_SIZE];
+        self.stream.read_exact(&mut response).await?;
+        TrackingRate::parse(&response)
+    }
+
+    /// Sets the tracking frequency.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn set_tracking_rate(&mut self, rate: &TrackingRate) -> Result<(), Error> {
+        self.stream
+            .write_all(format!(":ST {}#", rate).as_bytes())
+            .await?;
+        let mut response = [0u8; 1];
+        self.stream.read_exact(&mut response).await?;
+        Error::parse(&response)
+    }
+
+    /// Tracks at the sidereal rate of the quartz clock.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn track_sidereal(&mut self) -> Result<(), Error> {
+        self.stream.write_all(b":TQ#").await?;
+        Ok(())
+    }
+
+    /// Tracks at the manually set frequency.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn track_manual(&mut self) -> Result<(), Error> {
+        self.stream.write_all(b":TM#").await?;
+        Ok(())
+    }
+
+    /// Increments the manual tracking frequency by 0.1 Hz.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn increment_tracking_rate(&mut self) -> Result<(), Error> {
+        self.stream.write_all(b":T+#").await?;
+        Ok(())
+    }
+
+    /// Decrements the manual tracking frequency by 0.1 Hz.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn decrement_tracking_rate(&mut self) -> Result<(), Error> {
+        self.stream.write_all(b":T-#").await?;
+        Ok(())
+    }
+}
+
+/// GPS
+///
+/// **NOTE**: Only supported by LX200GPS telescopes, see [Client::product].
+impl<T: Stream> Client<T> {
+    /// Turns on power to the GPS receiver.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn gps_on(&mut self) -> Result<(), Error> {
+        self.require_product(&[Product::Lx200Gps])?;
+        self.stream.write_all(GPS_ON).await?;
+        Ok(())
+    }
+
+    /// Turns off power to the GPS receiver.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn gps_off(&mut self) -> Result<(), Error> {
+        self.require_product(&[Product::Lx200Gps])?;
+        self.stream.write_all(GPS_OFF).await?;
+        Ok(())
+    }
+
+    /// Gets the next sentence from the GPS receiver.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn get_nmea_sentence(&mut self) -> Result<NmeaSentence, Error> {
+        self.require_product(&[Product::Lx200Gps])?;
+        self.stream.write_all(GET_NMEA_SENTENCE).await?;
+        let response = self.read_response().await?;
+        NmeaSentence::parse(&response)
+    }
+
+    /// Powers up the GPS receiver and polls its sentences every `poll_interval` until it reports a fix
+    /// or `timeout` elapses.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn wait_for_gps_fix(
+        &mut self,
+        timeout: Duration,
+        poll_interval: Duration,
+    ) -> Result<NmeaSentence, Error> {
+        self.gps_on().await?;
+        let start = Instant::now();
+        loop {
+            let sentence = self.get_nmea_sentence().await?;
+            if sentence.has_fix() {
+                return Ok(sentence);
+            }
+            if start.elapsed() >= timeout {
+                return Err(Error::Timeout);
+            }
+            delay(poll_interval).await;
+        }
+    }
+
+    /// Powers up the GPS receiver and updates the system time from it, failing with [Error::Timeout]
+    /// if the update does not finish within `timeout`.
+    ///
+    /// **NOTE**: This may take several minutes, during which the handbox is unavailable.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn update_time_from_gps(&mut self, timeout: Duration) -> Result<(), Error> {
+        self.require_product(&[Product::Lx200Gps])?;
+        self.stream.write_all(UPDATE_TIME_FROM_GPS).await?;
+        let response = self.read_status_within(timeout).await?;
+        Error::parse(&response)
+    }
+}
+
+/// Home Position
+impl<T: Stream> Client<T> {
+    /// Slews the telescope to the park position and stops tracking.
+    ///
+    /// **NOTE**: Only supported by Autostar and LX200GPS telescopes.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn park(&mut self) -> Result<(), Error> {
+        self.require_product(&[Product::Autostar, Product::Lx200Gps])?;
+        self.stream.write_all(b":hP#").await?;
+        Ok(())
+    }
+
+    /// Seeks the home position and stores the encoder values of the aligned telescope as the park position.
+    ///
+    /// **NOTE**: Only supported by Autostar and LX200GPS telescopes.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn set_park_position(&mut self) -> Result<(), Error> {
+        self.require_product(&[Product::Autostar, Product::Lx200Gps])?;
+        self.stream.write_all(b":hS#").await?;
+        Ok(())
+    }
+
+    /// Seeks the home position and aligns the telescope from the stored encoder values.
+    /// Use [Client::home_status] to follow the progress of the search.
+    ///
+    /// **NOTE**: Only supported by Autostar and LX200GPS telescopes.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn find_home(&mut self) -> Result<(), Error> {
+        self.require_product(&[Product::Autostar, Product::Lx200Gps])?;
+        self.stream.write_all(b":hF#").await?;
+        Ok(())
+    }
+
+    /// Gets the status of the home search.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn home_status(&mut self) -> Result<HomeStatus, Error> {
+        self.stream.write_all(GET_HOME_STATUS).await?;
+        let mut response = [0u8; GET_HOME_STATUS_SIZE];
+        self.stream.read_exact(&mut response).await?;
+        HomeStatus::parse(&response)
+    }
+}
+
+/// Handbox
+impl<T: Stream> Client<T> {
+    /// Presses a key on the handbox.
+    ///
+    /// **NOTE**: Only supported by Autostar telescopes.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn press_key(&mut self, key: &HandboxKey) -> Result<(), Error> {
+        self.require_product(&[Product::Autostar])?;
+        self.stream.write_all(&key.press_request()).await?;
+        Ok(())
+    }
+
+    /// Reads the text shown on the handbox display.
+    ///
+    /// **NOTE**: Only supported by Autostar telescopes.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn read_display(&mut self) -> Result<String, Error> {
+        self.require_product(&[Product::Autostar])?;
+        self.stream.write_all(GET_DISPLAY).await?;
+        let response = self.read_response().await?;
+        decode_display(&response)
+    }
+}
+
+/// Power
+///
+/// **NOTE**: Only supported by Autostar and LX200GPS telescopes.
+impl<T: Stream> Client<T> {
+    /// Puts the telescope to sleep, powering off the motors and display.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn sleep(&mut self) -> Result<(), Error> {
+        self.require_product(&[Product::Autostar, Product::Lx200Gps])?;
+        self.stream.write_all(b":hN#").await?;
+        Ok(())
+    }
+
+    /// Wakes the telescope from sleep.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn wake(&mut self) -> Result<(), Error> {
+        self.require_product(&[Product::Autostar, Product::Lx200Gps])?;
+        self.stream.write_all(b":hW#").await?;
+        Ok(())
+    }
+
+    /// Initializes the telescope with the date and time of the clock, skipping the prompts of the handbox at startup.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn initialize(&mut self, clock: &Clock) -> Result<(), Error> {
+        self.require_product(&[Product::Autostar, Product::Lx200Gps])?;
+        self.stream.write_all(&clock.initialize_request()).await?;
+        let mut response = [0u8; 1];
+        self.stream.read_exact(&mut response).await?;
+        Error::parse(&response)
+    }
+}
+
+/// Backlash
+impl<T: Stream> Client<T> {
+    /// The anti-backlash last set through this client.
+    ///
+    /// **NOTE**: The telescope cannot be queried for it, so anything not set since connecting is [None]
+    /// even though the telescope keeps its own values.
+    pub fn last_set_backlash(&self) -> &BacklashSettings {
+        &self.backlash
+    }
+
+    /// Sets the anti-backlash of each axis that is [Some].
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn set_backlash(&mut self, settings: &BacklashSettings) -> Result<(), Error> {
+        for request in settings.set_requests() {
+            self.stream.write_all(&request).await?;
+        }
+        if let Some(altitude) = &settings.altitude {
+            self.backlash.altitude = Some(altitude.clone());
+        }
+        if let Some(azimuth) = &settings.azimuth {
+            self.backlash.azimuth = Some(azimuth.clone());
+        }
+        Ok(())
+    }
+}
+
+/// Focus Control
+impl<T: Stream> Client<T> {
+    /// Starts the focuser moving in the specified direction at the current speed.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn start_focus(&mut self, direction: &FocusDirection) -> Result<(), Error> {
+        self.stream
+            .write_all(format!(":F{}#", direction).as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    /// Halts focuser motion.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn stop_focus(&mut self) -> Result<(), Error> {
+        self.stream.write_all(b":FQ#").await?;
+        Ok(())
+    }
+
+    /// Sets the focuser speed.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn set_focus_speed(&mut self, speed: &FocusSpeed) -> Result<(), Error> {
+        self.stream
+            .write_all(format!(":F{}#", speed).as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    /// Moves the focuser in the specified direction for the duration and then halts it.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn focus_for(
+        &mut self,
+        direction: &FocusDirection,
+        duration: Duration,
+    ) -> Result<(), Error> {
+        self.start_focus(direction).await?;
+        delay(duration).await;
+        self.stop_focus().await
+    }
+}
+
+/// Reticle
+impl<T: Stream> Client<T> {
+    /// Steps the brightness of the illuminated reticle.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn step_reticle_brightness(&mut self, step: &BrightnessStep) -> Result<(), Error> {
+        self.stream
+            .write_all(format!(":B{}#", step).as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    /// Sets the flash rate of the illuminated reticle.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn set_reticle_flash(&mut self, mode: &FlashMode) -> Result<(), Error> {
+        self.stream
+            .write_all(format!(":B{}#", mode).as_bytes())
+            .await?;
+        Ok(())
+    }
+}
+
 /// Library / Objects
 impl<T: Stream> Client<T> {
     /// Gets object Right Ascension.
     #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
     pub async fn get_object_right_ascension(&mut self) -> Result<Ascension, Error> {
         self.stream.write_all(GET_OBJECT_RIGHT_ASCENSION).await?;
-        let mut response = [0u8; GET_OBJECT_RIGHT_ASCENSION_SIZE];
-        self.stream.read_exact(&mut response).await?;
+        let response = self.read_response().await?;
         Ascension::parse(&response)
     }
 
@@ -329,8 +1082,7 @@ impl<T: Stream> Client<T> {
     #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
     pub async fn get_object_declination(&mut self) -> Result<Declination, Error> {
         self.stream.write_all(GET_OBJECT_DECLANATION).await?;
-        let mut response = [0u8; GET_DECLANATION_SIZE];
-        self.stream.read_exact(&mut response).await?;
+        let response = self.read_response().await?;
         Declination::parse(&response)
     }
 
@@ -341,7 +1093,7 @@ impl<T: Stream> Client<T> {
         right_ascension: &Ascension,
     ) -> Result<(), Error> {
         self.stream
-            .write_all(format!(":Sr {}#", right_ascension).as_bytes())
+            .write_all(&right_ascension.set_request(&self.precision))
             .await?;
         let mut response = [0u8; 1];
         self.stream.read_exact(&mut response).await?;
@@ -351,27 +1103,208 @@ impl<T: Stream> Client<T> {
     /// Sets object declination.
     #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
     pub async fn set_object_declination(&mut self, declination: &Declination) -> Result<(), Error> {
-        self.stream.write_all(&declination.set_request()).await?;
+        self.stream
+            .write_all(&declination.set_request(&self.precision))
+            .await?;
         let mut response = [0u8; 1];
         self.stream.read_exact(&mut response).await?;
         Error::parse(&response)
     }
 
-    /// Sync. Matches current telescope coordinates to the object coordinates.
+    /// Selects a Messier object as the current object, ready for [Client::slew].
     #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
-    pub async fn sync(&mut self) -> Result<(), Error> {
-        self.stream.write_all(SYNC).await?;
-        // TODO: sends a string indicating which object's coordinates were used.
+    pub async fn select_messier(&mut self, number: &ObjectNumber) -> Result<(), Error> {
+        self.stream
+            .write_all(format!(":LM {}#", number).as_bytes())
+            .await?;
         Ok(())
     }
+
+    /// Selects the deep sky catalog used by [Client::select_deep_sky].
+    ///
+    /// **NOTE**: Only supported by Autostar and LX200GPS telescopes.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn select_deep_sky_catalog(&mut self, catalog: &DeepSkyCatalog) -> Result<(), Error> {
+        self.require_product(&[Product::Autostar, Product::Lx200Gps])?;
+        self.stream
+            .write_all(format!(":Lo{}#", catalog).as_bytes())
+            .await?;
+        let mut response = [0u8; 1];
+        self.stream.read_exact(&mut response).await?;
+        Error::parse(&response)
+    }
+
+    /// Selects an object from the current deep sky catalog as the current object, ready for [Client::slew].
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn select_deep_sky(&mut self, number: &ObjectNumber) -> Result<(), Error> {
+        self.stream
+            .write_all(format!(":LC {}#", number).as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    /// Selects the star catalog used by [Client::select_star].
+    ///
+    /// **NOTE**: Only supported by Autostar and LX200GPS telescopes.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn select_star_catalog(&mut self, catalog: &StarCatalog) -> Result<(), Error> {
+        self.require_product(&[Product::Autostar, Product::Lx200Gps])?;
+        self.stream
+            .write_all(format!(":Ls{}#", catalog).as_bytes())
+            .await?;
+        let mut response = [0u8; 1];
+        self.stream.read_exact(&mut response).await?;
+        Error::parse(&response)
+    }
+
+    /// Selects a star from the current star catalog as the current object, ready for [Client::slew].
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn select_star(&mut self, number: &ObjectNumber) -> Result<(), Error> {
+        self.stream
+            .write_all(format!(":LS {}#", number).as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    /// Steps to the next object in the library that matches the find parameters and sets it as the current object.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn next_object(&mut self) -> Result<(), Error> {
+        self.stream.write_all(b":LN#").await?;
+        Ok(())
+    }
+
+    /// Steps to the previous object in the library and sets it as the current object.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn previous_object(&mut self) -> Result<(), Error> {
+        self.stream.write_all(b":LB#").await?;
+        Ok(())
+    }
+
+    /// Gets information about the current object such as type, magnitude and size.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn object_info(&mut self) -> Result<ObjectInfo, Error> {
+        self.stream.write_all(GET_OBJECT_INFO).await?;
+        let response = self.read_response().await?;
+        ObjectInfo::parse(&response)
+    }
+
+    /// Gets the parameters used by FIND/BROWSE to filter the library.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn get_find_parameters(&mut self) -> Result<FindParameters, Error> {
+        let quality = self.get_minimum_quality().await?;
+
+        self.stream.write_all(GET_BRIGHTER_LIMIT).await?;
+        let mut response = [0u8; GET_MAGNITUDE_LIMIT_SIZE];
+        self.stream.read_exact(&mut response).await?;
+        let brighter = FindParameters::parse_magnitude_limit(&response)?;
+
+        self.stream.write_all(GET_FAINTER_LIMIT).await?;
+        let mut response = [0u8; GET_MAGNITUDE_LIMIT_SIZE];
+        self.stream.read_exact(&mut response).await?;
+        let fainter = FindParameters::parse_magnitude_limit(&response)?;
+
+        self.stream.write_all(GET_LARGER_LIMIT).await?;
+        let mut response = [0u8; GET_SIZE_LIMIT_SIZE];
+        self.stream.read_exact(&mut response).await?;
+        let larger = FindParameters::parse_size_limit(&response)?;
+
+        self.stream.write_all(GET_SMALLER_LIMIT).await?;
+        let mut response = [0u8; GET_SIZE_LIMIT_SIZE];
+        self.stream.read_exact(&mut response).await?;
+        let smaller = FindParameters::parse_size_limit(&response)?;
+
+        self.stream.write_all(GET_OBJECT_TYPES).await?;
+        let mut response = [0u8; GET_OBJECT_TYPES_SIZE];
+        self.stream.read_exact(&mut response).await?;
+        let object_types = ObjectTypes::parse(&response)?;
+
+        Ok(FindParameters {
+            quality,
+            brighter,
+            fainter,
+            larger,
+            smaller,
+            object_types,
+        })
+    }
+
+    /// Sets the parameters used by FIND/BROWSE to filter the library.
+    ///
+    /// **NOTE**: The minimum quality can only be stepped through its cycle, so it is stepped until it matches.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn set_find_parameters(&mut self, parameters: &FindParameters) -> Result<(), Error> {
+        let requests = [
+            format!(":Sb {}#", parameters.brighter),
+            format!(":Sf {}#", parameters.fainter),
+            format!(":Sl {}#", parameters.larger),
+            format!(":Ss {}#", parameters.smaller),
+            format!(":Sy {}#", parameters.object_types),
+        ];
+        for request in requests {
+            self.stream.write_all(request.as_bytes()).await?;
+            let mut response = [0u8; 1];
+            self.stream.read_exact(&mut response).await?;
+            Error::parse(&response)?;
+        }
+
+        // There are seven quality levels, so a full cycle must pass the requested one.
+        for _ in 0..7 {
+            if self.get_minimum_quality().await? == parameters.quality {
+                return Ok(());
+            }
+            self.stream.write_all(STEP_MINIMUM_QUALITY).await?;
+        }
+        Err(Error::Status)
+    }
+
+    /// Gets the minimum quality used by FIND/BROWSE.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    async fn get_minimum_quality(&mut self) -> Result<ObjectQuality, Error> {
+        self.stream.write_all(GET_MINIMUM_QUALITY).await?;
+        let mut response = [0u8; GET_MINIMUM_QUALITY_SIZE];
+        self.stream.read_exact(&mut response).await?;
+        ObjectQuality::parse(&response)
+    }
+
+    /// Sets the target altitude for [Client::slew_altaz].
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn set_target_altitude(&mut self, altitude: &Altitude) -> Result<(), Error> {
+        self.stream
+            .write_all(&altitude.set_request(&self.precision))
+            .await?;
+        let mut response = [0u8; 1];
+        self.stream.read_exact(&mut response).await?;
+        Error::parse(&response)
+    }
+
+    /// Sets the target azimuth for [Client::slew_altaz].
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn set_target_azimuth(&mut self, azimuth: &Azimuth) -> Result<(), Error> {
+        self.stream
+            .write_all(&azimuth.set_request(&self.precision))
+            .await?;
+        let mut response = [0u8; 1];
+        self.stream.read_exact(&mut response).await?;
+        Error::parse(&response)
+    }
+
+    /// Sync. Matches current telescope coordinates to the object coordinates and yields which object's coordinates were used.
+    #[cfg_attr(not(feature = "tokio"), maybe_async::must_be_sync)]
+    pub async fn sync(&mut self) -> Result<SyncResult, Error> {
+        self.stream.write_all(SYNC).await?;
+        let response = self.read_response().await?;
+        SyncResult::parse(&response)
+    }
 }
 
 #[cfg(feature = "io")]
 impl Client<Box<dyn serialport::SerialPort>> {
+    /// Opens the port with [RESPONSE_TIMEOUT] as its read timeout.
     pub fn open(port: &str) -> serialport::Result<Self> {
         serialport::new(port, 9600)
+            .timeout(RESPONSE_TIMEOUT)
             .open()
-            .map(|stream| Client { stream })
+            .map(Client::new)
     }
 
     /// Uses `/dev/ttyUSB0` as the default port
@@ -382,8 +1315,11 @@ impl Client<Box<dyn serialport::SerialPort>> {
 
 #[cfg(feature = "io")]
 impl Client<std::net::TcpStream> {
+    /// Connects with [RESPONSE_TIMEOUT] as the read timeout.
     pub fn connect(addr: std::net::Ipv4Addr) -> std::io::Result<Self> {
-        std::net::TcpStream::connect((addr, 9998)).map(|stream| Client { stream })
+        let stream = std::net::TcpStream::connect((addr, 9998))?;
+        stream.set_read_timeout(Some(RESPONSE_TIMEOUT))?;
+        Ok(Client::new(stream))
     }
 }
 
@@ -405,13 +1341,158 @@ impl DerefMut for BoxedClient {
 
 #[cfg(all(test, not(feature = "tokio")))]
 mod tests {
-    use std::io::Cursor;
+    use std::io::{Cursor, Read, Write};
 
-    use crate::Client;
+    use crate::{
+        formats::{mags::Magnitude, mins::ArcMinutes},
+        requests::{
+            find::{FindParameters, ObjectTypes},
+            firmware::Product,
+            library::ObjectQuality,
+            Axis, SlewStatus, SyncResult,
+        },
+        Client, Error,
+    };
+
+    /// Replays canned responses and records what was written.
+    struct Replay {
+        responses: Cursor<Vec<u8>>,
+        written: Vec<u8>,
+    }
+
+    impl Replay {
+        fn new(responses: &[u8]) -> Self {
+            Replay {
+                responses: Cursor::new(responses.to_vec()),
+                written: Vec::new(),
+            }
+        }
+    }
+
+    impl Read for Replay {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            self.responses.read(buf)
+        }
+    }
+
+    impl Write for Replay {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.written.write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
 
     #[test]
     fn test_init() {
         let cursor = Cursor::new(Vec::<u8>::new());
         let _ = Client::new(cursor);
     }
+
+    #[test]
+    fn test_identify_classic_without_reply() {
+        /// Times out on everything but `:GR#`, like a classic LX200 asked for its product name.
+        struct Classic(Cursor<Vec<u8>>);
+
+        impl Read for Classic {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                match self.0.read(buf)? {
+                    0 => Err(std::io::ErrorKind::TimedOut.into()),
+                    read => Ok(read),
+                }
+            }
+        }
+
+        impl Write for Classic {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                if buf == b":GR#" {
+                    self.0 = Cursor::new(b"05:35.3#".to_vec());
+                }
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut client = Client::new(Classic(Cursor::new(Vec::new())));
+        let info = client.identify().unwrap();
+        assert_eq!(info.product, Product::Lx200Classic);
+        assert_eq!(client.product(), Some(&Product::Lx200Classic));
+    }
+
+    #[test]
+    fn test_sync_reads_reply() {
+        let mut client = Client::new(Replay::new(b" M31 EX GAL MAG 3.5 SZ178.0'#1"));
+        assert_eq!(
+            client.sync().unwrap(),
+            SyncResult::Object("M31 EX GAL MAG 3.5 SZ178.0'".to_string())
+        );
+        assert_eq!(client.get_mut().written, b":CM#");
+
+        // The next response is not shifted by the sync reply.
+        let mut response = [0u8; 1];
+        client.get_mut().read_exact(&mut response).unwrap();
+        assert_eq!(&response, b"1");
+    }
+
+    #[test]
+    fn test_slew_reads_message() {
+        let mut client = Client::new(Replay::new(b"1Object Below Horizon #0"));
+        assert_eq!(
+            client.slew().unwrap(),
+            SlewStatus::BelowHorizon("Object Below Horizon".to_string())
+        );
+        assert_eq!(client.slew().unwrap(), SlewStatus::CanComplete);
+    }
+
+    fn find_parameters(quality: ObjectQuality) -> FindParameters {
+        FindParameters {
+            quality,
+            brighter: Magnitude::new(-2.0).unwrap(),
+            fainter: Magnitude::new(12.5).unwrap(),
+            larger: ArcMinutes::new(5).unwrap(),
+            smaller: ArcMinutes::new(200).unwrap(),
+            object_types: ObjectTypes::parse(b"GPDCO#").unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_set_find_parameters_steps_quality() {
+        let mut client = Client::new(Replay::new(b"11111SU#EX#VG#GD#"));
+        client
+            .set_find_parameters(&find_parameters(ObjectQuality::Good))
+            .unwrap();
+        let written = String::from_utf8_lossy(&client.get_mut().written).into_owned();
+        assert!(written.starts_with(":Sb -02.0#:Sf +12.5#:Sl 005#:Ss 200#:Sy GPDCO#"));
+        assert_eq!(written.matches(":Sq#").count(), 3);
+    }
+
+    #[test]
+    fn test_set_find_parameters_fails_after_full_cycle() {
+        let mut client = Client::new(Replay::new(b"11111SU#SU#SU#SU#SU#SU#SU#"));
+        assert!(matches!(
+            client.set_find_parameters(&find_parameters(ObjectQuality::Good)),
+            Err(Error::Status)
+        ));
+        assert_eq!(
+            String::from_utf8_lossy(&client.get_mut().written)
+                .matches(":Sq#")
+                .count(),
+            7
+        );
+    }
+
+    #[test]
+    fn test_set_axis_rate_request() {
+        let mut client = Client::new(Replay::new(b""));
+        client.set_axis_rate(&Axis::RightAscension, 2.5).unwrap();
+        client.set_axis_rate(&Axis::Declination, 0.25).unwrap();
+        assert_eq!(client.get_mut().written, b":RA02.5#:RE00.2#");
+        assert!(client.set_axis_rate(&Axis::Declination, 8.5).is_err());
+        assert!(client.set_axis_rate(&Axis::Declination, -1.0).is_err());
+    }
 }
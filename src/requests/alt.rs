@@ -1,24 +1,73 @@
-use nom::bytes::complete::tag;
+use nom::{branch::alt, bytes::complete::tag, combinator::opt, sequence::preceded};
 
 use crate::{
     error::Error,
-    formats::{degs::SignedDegrees, mins::Minutes},
+    formats::{degs::SignedDegrees, mins::Minutes, secs::Seconds},
 };
 
+use super::Precision;
+
 pub const GET_ALTITUDE: &[u8] = b":GA#";
+#[deprecated(
+    note = "replies in high precision also carry arc seconds, read up to the `#` terminator instead"
+)]
 pub const GET_ALTITUDE_SIZE: usize = b"sDD\xDFMM#".len();
 
 pub struct Altitude {
     pub degrees: SignedDegrees,
     pub minutes: Minutes,
+    /// Only reported by a telescope in [Precision::High].
+    pub arc_seconds: Option<Seconds>,
 }
 
 impl Altitude {
+    /// Parses either the low precision `sDD*MM#` or the high precision `sDD*MM'SS#` format.
     pub fn parse(input: &[u8]) -> Result<Self, Error> {
         let (input, degrees) = SignedDegrees::from_bytes(input)?;
         let (input, _) = tag(b"\xDF")(input)?;
         let (input, minutes) = Minutes::from_bytes(input)?;
+        let (input, arc_seconds) =
+            opt(preceded(alt((tag("'"), tag(":"))), Seconds::from_bytes))(input)?;
         let (_, _) = tag("#")(input)?;
-        Ok(Altitude { degrees, minutes })
+        Ok(Altitude {
+            degrees,
+            minutes,
+            arc_seconds,
+        })
+    }
+
+    /// Creates the set request in the format expected by a telescope in the given precision.
+    pub fn set_request(&self, precision: &Precision) -> Vec<u8> {
+        let mut bytes = b":Sa ".to_vec();
+        bytes.extend(self.degrees.to_string().as_bytes());
+        bytes.push(b'\xDF');
+        bytes.extend(self.minutes.to_string().as_bytes());
+        if let Precision::High = precision {
+            bytes.push(b':');
+            match &self.arc_seconds {
+                Some(arc_seconds) => bytes.extend(arc_seconds.to_string().as_bytes()),
+                None => bytes.extend(b"00"),
+            }
+        }
+        bytes.push(b'#');
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formats::{degs::SignedDegrees, mins::Minutes};
+
+    use super::{Altitude, Precision};
+
+    #[test]
+    fn test_set_request_pads_degrees() {
+        let altitude = Altitude {
+            degrees: SignedDegrees::new(5).unwrap(),
+            minutes: Minutes::new(30).unwrap(),
+            arc_seconds: None,
+        };
+        assert_eq!(altitude.set_request(&Precision::Low), b":Sa +05\xDF30#");
+        assert_eq!(altitude.set_request(&Precision::High), b":Sa +05\xDF30:00#");
     }
 }
@@ -1,86 +1,121 @@
 use std::fmt::Display;
 
-use nom::bytes::complete::tag;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    combinator::{map, opt},
+    sequence::preceded,
+};
 
-#[cfg(feature = "roms")]
-use crate::formats::secs::Seconds;
 use crate::{
     error::Error,
     formats::{
         hrs::Hours,
         mins::{Minutes, Tenths},
+        secs::Seconds,
     },
 };
 
+use super::Precision;
+
 pub const GET_RIGHT_ASCENSION: &[u8] = b":GR#";
 pub const GET_OBJECT_RIGHT_ASCENSION: &[u8] = b":Gr#";
 
-#[cfg(not(feature = "roms"))]
-pub const GET_RIGHT_ASCENSION_SIZE: usize = b"+HH:MM.T#".len();
-#[cfg(feature = "roms")]
-pub const GET_RIGHT_ASCENSION_SIZE: usize = b"+HH:MM:SS#".len();
-#[cfg(not(feature = "roms"))]
-pub const GET_OBJECT_RIGHT_ASCENSION_SIZE: usize = b"HH:MM.T#".len();
-#[cfg(feature = "roms")]
-pub const GET_OBJECT_RIGHT_ASCENSION_SIZE: usize = b"HH:MM:SS#".len();
+/// Part of the minute, which depends on the [Precision] of the telescope.
+pub enum MinuteFraction {
+    /// `T` of the low precision `HH:MM.T` format.
+    Tenths(Tenths),
+    /// `SS` of the high precision `HH:MM:SS` format.
+    Seconds(Seconds),
+}
 
 pub struct Ascension {
     pub hours: Hours,
     pub minutes: Minutes,
-    #[cfg(not(feature = "roms"))]
-    pub tenths_minutes: Tenths,
-    #[cfg(feature = "roms")]
-    pub seconds: Seconds,
+    pub fraction: MinuteFraction,
 }
 
 impl Ascension {
-    /// Parses [AscensionSeconds] from byte array corresponding to the [Right Ascension](https://en.wikipedia.org/wiki/Right_ascension) orientation.
+    /// Parses [Ascension] from byte array corresponding to the [Right Ascension](https://en.wikipedia.org/wiki/Right_ascension) orientation.
     /// This correspondds to horizontal rotation when looking at the sky.
     ///
+    /// Both the low precision `HH:MM.T#` and high precision `HH:MM:SS#` formats are accepted.
+    ///
     /// *NOTE* A Mapug-Astronomy post indicated that for the 3.34L ROMS the format is +HH:MM:SS# for RA
-    #[cfg(feature = "roms")]
     pub fn parse(input: &[u8]) -> Result<Self, Error> {
         let (input, _) = opt(tag("+"))(input)?;
         let (input, hours) = Hours::from_bytes(input)?;
         let (input, _) = tag(":")(input)?;
         let (input, minutes) = Minutes::from_bytes(input)?;
-        let (input, _) = tag(":")(input)?;
-        let (input, seconds) = Seconds::from_bytes(input)?;
+        let (input, fraction) = alt((
+            map(
+                preceded(tag("."), Tenths::from_bytes),
+                MinuteFraction::Tenths,
+            ),
+            map(
+                preceded(tag(":"), Seconds::from_bytes),
+                MinuteFraction::Seconds,
+            ),
+        ))(input)?;
         let (_, _) = tag("#")(input)?;
         Ok(Ascension {
             hours,
             minutes,
-            seconds,
+            fraction,
         })
     }
 
-    /// Parses [Ascension] from byte array corresponding to the [Right Ascension](https://en.wikipedia.org/wiki/Right_ascension) orientation.
-    /// This correspondds to horizontal rotation when looking at the sky.
-    #[cfg(not(feature = "roms"))]
-    pub fn parse(input: &[u8]) -> Result<Self, Error> {
-        use nom::combinator::opt;
+    /// The precision of the format this was read in.
+    pub fn precision(&self) -> Precision {
+        match self.fraction {
+            MinuteFraction::Tenths(_) => Precision::Low,
+            MinuteFraction::Seconds(_) => Precision::High,
+        }
+    }
 
-        let (input, _) = opt(tag("+"))(input)?;
-        let (input, hours) = Hours::from_bytes(input)?;
-        let (input, _) = tag(":")(input)?;
-        let (input, minutes) = Minutes::from_bytes(input)?;
-        let (input, _) = tag(".")(input)?;
-        let (input, tenths_minutes) = Tenths::from_bytes(input)?;
-        let (_, _) = tag("#")(input)?;
-        Ok(Ascension {
-            hours,
-            minutes,
-            tenths_minutes,
-        })
+    /// Creates the set request in the format expected by a telescope in the given precision.
+    pub fn set_request(&self, precision: &Precision) -> Vec<u8> {
+        let fraction = match (&self.fraction, precision) {
+            (MinuteFraction::Tenths(tenths), Precision::Low) => format!(".{}", tenths),
+            (MinuteFraction::Tenths(tenths), Precision::High) => {
+                format!(":{}", tenths.to_seconds())
+            }
+            (MinuteFraction::Seconds(seconds), Precision::Low) => {
+                format!(".{}", seconds.to_tenths())
+            }
+            (MinuteFraction::Seconds(seconds), Precision::High) => format!(":{}", seconds),
+        };
+        format!(":Sr {}:{}{}#", self.hours, self.minutes, fraction).into_bytes()
     }
 }
 
 impl Display for Ascension {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        #[cfg(not(feature = "roms"))]
-        write!(f, "{}:{}.{}", self.hours, self.minutes, self.tenths_minutes)?;
-        #[cfg(feature = "roms")]
-        write!(f, "{}:{}:{}", self.hours, self.minutes, self.seconds)?;
-        Ok(())
+        match &self.fraction {
+            MinuteFraction::Tenths(tenths) => {
+                write!(f, "{}:{}.{}", self.hours, self.minutes, tenths)
+            }
+            MinuteFraction::Seconds(seconds) => {
+                write!(f, "{}:{}:{}", self.hours, self.minutes, seconds)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::requests::Precision;
+
+    use super::Ascension;
+
+    #[test]
+    fn test_parse_either_precision() {
+        let low = Ascension::parse(b"05:35.3#").unwrap();
+        assert_eq!(low.precision(), Precision::Low);
+        assert_eq!(low.set_request(&Precision::High), b":Sr 05:35:18#");
+
+        let high = Ascension::parse(b"05:35:18#").unwrap();
+        assert_eq!(high.precision(), Precision::High);
+        assert_eq!(high.set_request(&Precision::Low), b":Sr 05:35.3#");
     }
 }
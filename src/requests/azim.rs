@@ -1,24 +1,71 @@
-use nom::bytes::complete::tag;
+use nom::{branch::alt, bytes::complete::tag, combinator::opt, sequence::preceded};
 
 use crate::{
     error::Error,
-    formats::{degs::Degrees, mins::Minutes},
+    formats::{degs::Degrees, mins::Minutes, secs::Seconds},
 };
 
+use super::Precision;
+
 pub const GET_AZMITH: &[u8] = b":GZ#";
+#[deprecated(
+    note = "replies in high precision also carry arc seconds, read up to the `#` terminator instead"
+)]
 pub const GET_AZMITH_SIZE: usize = b"DDD\xDFMM#".len();
 
 pub struct Azimuth {
     pub degrees: Degrees,
     pub minutes: Minutes,
+    /// Only reported by a telescope in [Precision::High].
+    pub arc_seconds: Option<Seconds>,
 }
 
 impl Azimuth {
+    /// Parses either the low precision `DDD*MM#` or the high precision `DDD*MM'SS#` format.
     pub fn parse(input: &[u8]) -> Result<Self, Error> {
         let (input, degrees) = Degrees::from_bytes(input)?;
         let (input, _) = tag(b"\xDF")(input)?;
         let (input, minutes) = Minutes::from_bytes(input)?;
+        let (input, arc_seconds) =
+            opt(preceded(alt((tag("'"), tag(":"))), Seconds::from_bytes))(input)?;
         let (_, _) = tag("#")(input)?;
-        Ok(Azimuth { degrees, minutes })
+        Ok(Azimuth {
+            degrees,
+            minutes,
+            arc_seconds,
+        })
+    }
+
+    /// Creates the set request in the format expected by a telescope in the given precision.
+    pub fn set_request(&self, precision: &Precision) -> Vec<u8> {
+        let mut bytes = b":Sz ".to_vec();
+        bytes.extend(self.degrees.to_string().as_bytes());
+        bytes.push(b'\xDF');
+        bytes.extend(self.minutes.to_string().as_bytes());
+        if let Precision::High = precision {
+            bytes.push(b':');
+            match &self.arc_seconds {
+                Some(arc_seconds) => bytes.extend(arc_seconds.to_string().as_bytes()),
+                None => bytes.extend(b"00"),
+            }
+        }
+        bytes.push(b'#');
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Azimuth;
+
+    #[test]
+    fn test_parse_either_precision() {
+        let low = Azimuth::parse(b"123\xDF45#").unwrap();
+        assert_eq!(low.degrees.to_string(), "123");
+        assert_eq!(low.minutes.to_string(), "45");
+        assert!(low.arc_seconds.is_none());
+
+        let high = Azimuth::parse(b"123\xDF45'30#").unwrap();
+        assert_eq!(high.arc_seconds.unwrap().to_string(), "30");
     }
 }
@@ -0,0 +1,43 @@
+use std::fmt::Display;
+
+/// Represents the anti-backlash `dd` in arc seconds from `00-99`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Backlash(u8);
+
+impl Backlash {
+    pub const fn new(val: u8) -> Option<Self> {
+        if val > 99 {
+            return None;
+        }
+        Some(Backlash(val))
+    }
+}
+
+impl Display for Backlash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}", self.0)
+    }
+}
+
+/// Anti-backlash of both axes, where [None] is left unchanged when set.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BacklashSettings {
+    /// Altitude in alt-az mode, declination in polar mode.
+    pub altitude: Option<Backlash>,
+    /// Azimuth in alt-az mode, Right Ascension in polar mode.
+    pub azimuth: Option<Backlash>,
+}
+
+impl BacklashSettings {
+    pub fn set_requests(&self) -> Vec<Vec<u8>> {
+        let altitude = self
+            .altitude
+            .iter()
+            .map(|backlash| format!(":$BA{}#", backlash).into_bytes());
+        let azimuth = self
+            .azimuth
+            .iter()
+            .map(|backlash| format!(":$BZ{}#", backlash).into_bytes());
+        altitude.chain(azimuth).collect()
+    }
+}
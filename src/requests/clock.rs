@@ -0,0 +1,152 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{
+    error::Error,
+    formats::{
+        days::Days, hrs::Hours, hrs::SignedHours, mins::Minutes, months::Months, secs::Seconds,
+        yrs::Years,
+    },
+};
+
+use super::{cal::Date, time::Time};
+
+const SECONDS_PER_HOUR: i64 = 60 * 60;
+const SECONDS_PER_DAY: i64 = 24 * SECONDS_PER_HOUR;
+
+/// Local date and time with its offset from UTC, used by [crate::Client::set_clock].
+pub struct Clock {
+    pub date: Date,
+    /// Local time in 24 hour format.
+    pub time: Time,
+    /// Offset from UTC in hours without daylight saving, e.g. `-5` for EST.
+    pub utc_offset: i8,
+    pub daylight_saving: bool,
+}
+
+impl Clock {
+    /// Creates the clock from an instant and the standard offset from UTC in hours, e.g. `-5` for EST.
+    pub fn new(now: SystemTime, utc_offset: i8, daylight_saving: bool) -> Result<Self, Error> {
+        let utc = now
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| Error::Format("time is before the unix epoch".to_string()))?;
+        let offset = (utc_offset as i64 + daylight_saving as i64) * SECONDS_PER_HOUR;
+        Self::from_parts(utc.as_secs() as i64, offset, daylight_saving)
+    }
+
+    /// Creates the clock from a [chrono::DateTime], whose offset already includes daylight saving.
+    #[cfg(feature = "chrono")]
+    pub fn from_chrono<Tz: chrono::TimeZone>(
+        now: &chrono::DateTime<Tz>,
+        daylight_saving: bool,
+    ) -> Result<Self, Error> {
+        use chrono::Offset;
+
+        let offset = now.offset().fix().local_minus_utc() as i64;
+        Self::from_parts(now.timestamp(), offset, daylight_saving)
+    }
+
+    /// Creates the clock from a [time::OffsetDateTime], whose offset already includes daylight saving.
+    #[cfg(feature = "time")]
+    pub fn from_time(now: &time::OffsetDateTime, daylight_saving: bool) -> Result<Self, Error> {
+        let offset = now.offset().whole_seconds() as i64;
+        Self::from_parts(now.unix_timestamp(), offset, daylight_saving)
+    }
+
+    /// `offset` is in seconds and includes daylight saving.
+    fn from_parts(utc: i64, offset: i64, daylight_saving: bool) -> Result<Self, Error> {
+        if offset % SECONDS_PER_HOUR != 0 {
+            return Err(Error::Format(format!(
+                "offset of {}s is not a whole number of hours",
+                offset
+            )));
+        }
+        let utc_offset = offset / SECONDS_PER_HOUR - daylight_saving as i64;
+
+        let local = utc + offset;
+        let (year, month, day) = civil_from_days(local.div_euclid(SECONDS_PER_DAY));
+        // The two digit year covers 1992 through 2091.
+        if !(1992..=2091).contains(&year) {
+            return Err(Error::Format(format!("year {} is out of range", year)));
+        }
+        let seconds = local.rem_euclid(SECONDS_PER_DAY);
+
+        let invalid = || Error::Format("invalid clock".to_string());
+        Ok(Clock {
+            date: Date {
+                months: Months::new(month).ok_or_else(invalid)?,
+                days: Days::new(day).ok_or_else(invalid)?,
+                years: Years::new((year % 100) as u8).ok_or_else(invalid)?,
+            },
+            time: Time {
+                hours: Hours::new((seconds / SECONDS_PER_HOUR) as u8).ok_or_else(invalid)?,
+                minutes: Minutes::new((seconds % SECONDS_PER_HOUR / 60) as u8)
+                    .ok_or_else(invalid)?,
+                seconds: Seconds::new((seconds % 60) as u8).ok_or_else(invalid)?,
+            },
+            utc_offset: i8::try_from(utc_offset).map_err(|_| invalid())?,
+            daylight_saving,
+        })
+    }
+
+    /// Creates the `:hI` request that bypasses the date and time prompts of the handbox at startup.
+    pub fn initialize_request(&self) -> Vec<u8> {
+        format!(
+            ":hI{}{}{}{}{}{}#",
+            self.date.years,
+            self.date.months,
+            self.date.days,
+            self.time.hours,
+            self.time.minutes,
+            self.time.seconds
+        )
+        .into_bytes()
+    }
+
+    /// The offset in the LX200 convention, which is the hours added to local time to get UTC.
+    /// This is the inverse of the usual sign, e.g. `+05` for EST.
+    ///
+    /// Telescopes without a daylight saving setting need it folded into the offset.
+    pub fn gmt_offset(&self, include_daylight_saving: bool) -> Result<SignedHours, Error> {
+        let dst = (include_daylight_saving && self.daylight_saving) as i8;
+        SignedHours::new(-(self.utc_offset + dst))
+            .ok_or_else(|| Error::Format(format!("invalid utc offset {}", self.utc_offset)))
+    }
+}
+
+/// Converts days since the unix epoch into `(year, month, day)`.
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let year = yoe + era * 400 + (month <= 2) as i64;
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::Clock;
+
+    #[test]
+    fn test_clock_local_time() {
+        // 2025-01-01T03:30:15Z in EST is 2024-12-31 22:30:15.
+        let now = UNIX_EPOCH + Duration::from_secs(1_735_702_215);
+        let clock = Clock::new(now, -5, false).unwrap();
+        assert_eq!(clock.date.to_string(), "12/31/24");
+        assert_eq!(clock.time.to_string(), "22:30:15");
+        assert_eq!(clock.gmt_offset(true).unwrap().to_string(), "+05");
+
+        let clock = Clock::new(now, -5, true).unwrap();
+        assert_eq!(clock.time.to_string(), "23:30:15");
+        assert_eq!(clock.gmt_offset(false).unwrap().to_string(), "+05");
+        assert_eq!(clock.gmt_offset(true).unwrap().to_string(), "+04");
+    }
+}
@@ -1,43 +1,32 @@
-use nom::bytes::complete::tag;
+use nom::{branch::alt, bytes::complete::tag, combinator::opt, sequence::preceded};
 
-#[cfg(feature = "roms")]
-use crate::formats::secs::Seconds;
 use crate::{
     error::Error,
-    formats::{degs::SignedDegrees, mins::Minutes},
+    formats::{degs::SignedDegrees, mins::Minutes, secs::Seconds},
 };
 
+use super::Precision;
+
 pub const GET_DECLANATION: &[u8] = b":GD#";
 pub const GET_OBJECT_DECLANATION: &[u8] = b":Gd#";
-#[cfg(not(feature = "roms"))]
-pub const GET_DECLANATION_SIZE: usize = b"sDD\xDFMM#".len();
-#[cfg(feature = "roms")]
-pub const GET_DECLANATION_SIZE: usize = b"sDD\xDFMM'SS#".len();
 
 pub struct Declination {
     pub degrees: SignedDegrees,
     pub minutes: Minutes,
-    #[cfg(feature = "roms")]
-    pub arc_seconds: Seconds,
+    /// Only reported by a telescope in [Precision::High].
+    pub arc_seconds: Option<Seconds>,
 }
 
 impl Declination {
-    pub fn parse(input: &[u8]) -> Result<Self, Error> {
-        let (input, degrees) = SignedDegrees::from_bytes(input)?;
-        let (input, _) = tag(b"\xDF")(input)?;
-        let (input, minutes) = Minutes::from_bytes(input)?;
-        let (_, _) = tag("#")(input)?;
-        Ok(Declination { degrees, minutes })
-    }
-
+    /// Parses either the low precision `sDD*MM#` or the high precision `sDD*MM'SS#` format.
+    ///
     /// A Mapug-Astronomy post indicated that for the 3.34L ROMS the format for Declination is `sDD*MM'SS#`.
-    #[cfg(feature = "roms")]
     pub fn parse(input: &[u8]) -> Result<Self, Error> {
         let (input, degrees) = SignedDegrees::from_bytes(input)?;
         let (input, _) = tag(b"\xDF")(input)?;
         let (input, minutes) = Minutes::from_bytes(input)?;
-        let (input, _) = tag("'")(input)?;
-        let (input, arc_seconds) = Seconds::from_bytes(input)?;
+        let (input, arc_seconds) =
+            opt(preceded(alt((tag("'"), tag(":"))), Seconds::from_bytes))(input)?;
         let (_, _) = tag("#")(input)?;
         Ok(Declination {
             degrees,
@@ -46,11 +35,19 @@ impl Declination {
         })
     }
 
-    pub fn set_request(&self) -> Vec<u8> {
+    /// Creates the set request in the format expected by a telescope in the given precision.
+    pub fn set_request(&self, precision: &Precision) -> Vec<u8> {
         let mut bytes = b":Sd ".to_vec();
         bytes.extend(self.degrees.to_string().as_bytes());
         bytes.push(b'\xDF');
         bytes.extend(self.minutes.to_string().as_bytes());
+        if let Precision::High = precision {
+            bytes.push(b':');
+            match &self.arc_seconds {
+                Some(arc_seconds) => bytes.extend(arc_seconds.to_string().as_bytes()),
+                None => bytes.extend(b"00"),
+            }
+        }
         bytes.push(b'#');
         bytes
     }
@@ -0,0 +1,50 @@
+use nom::bytes::complete::{tag, take_until};
+
+use crate::error::Error;
+
+pub const GET_DISTANCE_BARS: &[u8] = b":D#";
+
+/// Bars indicating the distance to the current object, only present while a slew is in progress.
+#[derive(Debug, PartialEq)]
+pub struct DistanceBars(usize);
+
+impl DistanceBars {
+    pub fn parse(input: &[u8]) -> Result<Self, Error> {
+        let (input, bars) = take_until("#")(input)?;
+        let (_, _) = tag("#")(input)?;
+        Ok(DistanceBars(
+            bars.iter().filter(|b| !b.is_ascii_whitespace()).count(),
+        ))
+    }
+
+    /// Number of bars displayed.
+    pub fn len(&self) -> usize {
+        self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn is_slewing(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DistanceBars;
+
+    #[test]
+    fn test_parse_counts_bars() {
+        let idle = DistanceBars::parse(b"#").unwrap();
+        assert!(idle.is_empty());
+        assert!(!idle.is_slewing());
+
+        let slewing = DistanceBars::parse(b"\x7F\x7F \x7F#").unwrap();
+        assert_eq!(slewing.len(), 3);
+        assert!(slewing.is_slewing());
+
+        assert!(DistanceBars::parse(b"\x7F\x7F").is_err());
+    }
+}
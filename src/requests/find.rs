@@ -0,0 +1,141 @@
+use std::fmt::{Display, Write};
+
+use nom::bytes::complete::{tag, take};
+
+use crate::{
+    error::Error,
+    formats::{mags::Magnitude, mins::ArcMinutes},
+};
+
+use super::library::ObjectQuality;
+
+pub const GET_MINIMUM_QUALITY: &[u8] = b":Gq#";
+pub const GET_MINIMUM_QUALITY_SIZE: usize = b"SU#".len();
+pub const STEP_MINIMUM_QUALITY: &[u8] = b":Sq#";
+pub const GET_BRIGHTER_LIMIT: &[u8] = b":Gb#";
+pub const GET_FAINTER_LIMIT: &[u8] = b":Gf#";
+pub const GET_MAGNITUDE_LIMIT_SIZE: usize = b"sMM.M#".len();
+pub const GET_LARGER_LIMIT: &[u8] = b":Gl#";
+pub const GET_SMALLER_LIMIT: &[u8] = b":Gs#";
+pub const GET_SIZE_LIMIT_SIZE: usize = b"NNN'#".len();
+pub const GET_OBJECT_TYPES: &[u8] = b":Gy#";
+pub const GET_OBJECT_TYPES_SIZE: usize = b"GPDCO#".len();
+
+/// Parameters used by FIND/BROWSE and [crate::Client::next_object] to filter the library.
+pub struct FindParameters {
+    /// Minimum object quality.
+    pub quality: ObjectQuality,
+    /// Brightest magnitude to include.
+    pub brighter: Magnitude,
+    /// Faintest magnitude to include.
+    pub fainter: Magnitude,
+    /// Smallest object size to include, as objects must be larger than it.
+    pub larger: ArcMinutes,
+    /// Largest object size to include, as objects must be smaller than it.
+    pub smaller: ArcMinutes,
+    pub object_types: ObjectTypes,
+}
+
+impl FindParameters {
+    /// Parses the brighter or fainter magnitude limit in the `sMM.M#` format.
+    pub fn parse_magnitude_limit(input: &[u8]) -> Result<Magnitude, Error> {
+        let (input, magnitude) = Magnitude::from_bytes(input)?;
+        let (_, _) = tag("#")(input)?;
+        Ok(magnitude)
+    }
+
+    /// Parses the larger or smaller size limit in the `NNN'#` format.
+    pub fn parse_size_limit(input: &[u8]) -> Result<ArcMinutes, Error> {
+        let (input, size) = ArcMinutes::from_bytes(input)?;
+        let (_, _) = tag("'#")(input)?;
+        Ok(size)
+    }
+}
+
+/// Classes of deep sky objects included in FIND/BROWSE, represented as `GPDCO`.
+///
+/// An upper case letter includes the class and a lower case letter excludes it.
+#[derive(Debug, PartialEq)]
+pub struct ObjectTypes {
+    pub galaxies: bool,
+    pub planetary_nebulas: bool,
+    pub diffuse_nebulas: bool,
+    pub globular_clusters: bool,
+    pub open_clusters: bool,
+}
+
+impl ObjectTypes {
+    pub fn parse(input: &[u8]) -> Result<Self, Error> {
+        let (input, types) = take(5usize)(input)?;
+        let (_, _) = tag("#")(input)?;
+        let included = |index: usize, letter: u8| match types[index] {
+            b if b == letter => Ok(true),
+            b if b == letter.to_ascii_lowercase() => Ok(false),
+            b => Err(Error::Format(format!(
+                "unknown object type {:?}",
+                b as char
+            ))),
+        };
+        Ok(ObjectTypes {
+            galaxies: included(0, b'G')?,
+            planetary_nebulas: included(1, b'P')?,
+            diffuse_nebulas: included(2, b'D')?,
+            globular_clusters: included(3, b'C')?,
+            open_clusters: included(4, b'O')?,
+        })
+    }
+}
+
+impl Display for ObjectTypes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let letter = |included: bool, letter: char| match included {
+            true => letter,
+            false => letter.to_ascii_lowercase(),
+        };
+        f.write_char(letter(self.galaxies, 'G'))?;
+        f.write_char(letter(self.planetary_nebulas, 'P'))?;
+        f.write_char(letter(self.diffuse_nebulas, 'D'))?;
+        f.write_char(letter(self.globular_clusters, 'C'))?;
+        f.write_char(letter(self.open_clusters, 'O'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FindParameters, ObjectTypes};
+
+    #[test]
+    fn test_object_types_round_trip() {
+        let types = ObjectTypes::parse(b"GpDcO#").unwrap();
+        assert_eq!(
+            types,
+            ObjectTypes {
+                galaxies: true,
+                planetary_nebulas: false,
+                diffuse_nebulas: true,
+                globular_clusters: false,
+                open_clusters: true,
+            }
+        );
+        assert_eq!(types.to_string(), "GpDcO");
+        assert!(ObjectTypes::parse(b"GPXCO#").is_err());
+    }
+
+    #[test]
+    fn test_parse_limits_checks_terminators() {
+        assert_eq!(
+            FindParameters::parse_magnitude_limit(b"+12.5#")
+                .unwrap()
+                .to_string(),
+            "+12.5"
+        );
+        assert!(FindParameters::parse_magnitude_limit(b"+12.5'").is_err());
+        assert_eq!(
+            FindParameters::parse_size_limit(b"030'#")
+                .unwrap()
+                .to_string(),
+            "030"
+        );
+        assert!(FindParameters::parse_size_limit(b"030##").is_err());
+    }
+}
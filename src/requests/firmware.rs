@@ -0,0 +1,55 @@
+use nom::bytes::complete::{tag, take_until};
+
+use crate::error::Error;
+
+use super::time::Time;
+
+pub const GET_PRODUCT_NAME: &[u8] = b":GVP#";
+pub const GET_FIRMWARE_VERSION: &[u8] = b":GVN#";
+pub const GET_FIRMWARE_DATE: &[u8] = b":GVD#";
+pub const GET_FIRMWARE_TIME: &[u8] = b":GVT#";
+
+/// Family of telescope, derived from the product name.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Product {
+    /// The classic LX200, which does not answer the product name query.
+    Lx200Classic,
+    Autostar,
+    /// LX200GPS and other Autostar II telescopes
+    Lx200Gps,
+    /// OnStep compatible controllers, which extend the command set.
+    OnStep,
+    Other(String),
+}
+
+impl Product {
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "Autostar" => Product::Autostar,
+            name if name.starts_with("LX2001") || name.contains("GPS") => Product::Lx200Gps,
+            name if name.starts_with("On-Step") || name.starts_with("OnStep") => Product::OnStep,
+            name => Product::Other(name.to_string()),
+        }
+    }
+}
+
+/// Identification reported by the telescope.
+///
+/// **NOTE**: The classic LX200 does not answer these queries, so only its [Product] is known.
+pub struct FirmwareInfo {
+    pub product: Product,
+    pub product_name: Option<String>,
+    pub version: Option<String>,
+    /// Date of the firmware, e.g. `Mar 24 2005`.
+    pub date: Option<String>,
+    pub time: Option<Time>,
+}
+
+impl FirmwareInfo {
+    /// Parses a `#` terminated string such as the product name, version and date.
+    pub fn parse_string(input: &[u8]) -> Result<String, Error> {
+        let (input, value) = take_until("#")(input)?;
+        let (_, _) = tag("#")(input)?;
+        Ok(String::from_utf8_lossy(value).trim().to_string())
+    }
+}
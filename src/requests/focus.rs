@@ -0,0 +1,32 @@
+use std::fmt::{Display, Write};
+
+/// Direction to drive the focuser.
+pub enum FocusDirection {
+    /// Moves the focus inward, toward the objective.
+    In,
+    /// Moves the focus outward, away from the objective.
+    Out,
+}
+
+impl Display for FocusDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FocusDirection::In => f.write_char('+'),
+            FocusDirection::Out => f.write_char('-'),
+        }
+    }
+}
+
+pub enum FocusSpeed {
+    Fast,
+    Slow,
+}
+
+impl Display for FocusSpeed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FocusSpeed::Fast => f.write_char('F'),
+            FocusSpeed::Slow => f.write_char('S'),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use nom::bytes::complete::{tag, take_until};
+
+use crate::error::Error;
+
+pub const GPS_ON: &[u8] = b":g+#";
+pub const GPS_OFF: &[u8] = b":g-#";
+pub const GET_NMEA_SENTENCE: &[u8] = b":gps#";
+pub const UPDATE_TIME_FROM_GPS: &[u8] = b":gT#";
+
+/// Sentence from the GPS receiver in the standard NMEA format, e.g. `$GPGGA,...`.
+#[derive(Debug, PartialEq)]
+pub struct NmeaSentence(String);
+
+impl NmeaSentence {
+    pub fn parse(input: &[u8]) -> Result<Self, Error> {
+        let (input, sentence) = take_until("#")(input)?;
+        let (_, _) = tag("#")(input)?;
+        Ok(NmeaSentence(
+            String::from_utf8_lossy(sentence).trim().to_string(),
+        ))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Whether the sentence reports a position fix.
+    /// Only `GGA` and `RMC` sentences carry the fix, any other sentence yields false.
+    pub fn has_fix(&self) -> bool {
+        let fields: Vec<&str> = self.0.split(',').collect();
+        match fields[0] {
+            kind if kind.ends_with("GGA") => fields.get(6).is_some_and(|q| !matches!(*q, "" | "0")),
+            kind if kind.ends_with("RMC") => fields.get(2) == Some(&"A"),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NmeaSentence;
+
+    #[test]
+    fn test_has_fix() {
+        let fixed = NmeaSentence::parse(
+            b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47#",
+        )
+        .unwrap();
+        assert!(fixed.has_fix());
+        let searching = NmeaSentence::parse(b"$GPGGA,123519,,,,,0,00,,,M,,M,,*66#").unwrap();
+        assert!(!searching.has_fix());
+
+        let valid = NmeaSentence::parse(
+            b"$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A#",
+        )
+        .unwrap();
+        assert!(valid.has_fix());
+        let void = NmeaSentence::parse(b"$GPRMC,123519,V,,,,,,,230394,,*33#").unwrap();
+        assert!(!void.has_fix());
+
+        let other = NmeaSentence::parse(b"$GPGSV,3,1,11,03,03,111,00*74#").unwrap();
+        assert!(!other.has_fix());
+    }
+}
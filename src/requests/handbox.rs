@@ -0,0 +1,99 @@
+use nom::bytes::complete::{tag, take_until};
+
+use crate::error::Error;
+
+pub const GET_DISPLAY: &[u8] = b":ED#";
+
+/// Represents a digit key from `0-9`
+pub struct Digit(u8);
+
+impl Digit {
+    pub const fn new(val: u8) -> Option<Self> {
+        if val > 9 {
+            return None;
+        }
+        Some(Digit(val))
+    }
+}
+
+/// Keys of the Autostar handbox, sent as the character code of the key.
+pub enum HandboxKey {
+    Digit(Digit),
+    Enter,
+    Mode,
+    GoTo,
+    ScrollUp,
+    ScrollDown,
+    Up,
+    Down,
+    Left,
+    Right,
+    Help,
+}
+
+impl HandboxKey {
+    /// Character code of the key.
+    pub fn code(&self) -> u8 {
+        match self {
+            HandboxKey::Digit(Digit(digit)) => b'0' + digit,
+            HandboxKey::Enter => 13,
+            HandboxKey::Mode => 9,
+            HandboxKey::GoTo => 24,
+            HandboxKey::ScrollUp => b'U',
+            HandboxKey::ScrollDown => b'D',
+            HandboxKey::Up => b'^',
+            HandboxKey::Down => b'v',
+            HandboxKey::Left => b'<',
+            HandboxKey::Right => b'>',
+            HandboxKey::Help => b'?',
+        }
+    }
+
+    pub fn press_request(&self) -> Vec<u8> {
+        format!(":EK{}#", self.code()).into_bytes()
+    }
+}
+
+/// Decodes a character of the handbox display, which follows the HD44780 LCD character set.
+pub fn decode_char(byte: u8) -> char {
+    match byte {
+        0x5C => '¥',
+        0x7E => '→',
+        0x7F => '←',
+        0xA5 => '·',
+        0xDF => '°',
+        0xE0 => 'α',
+        0xE2 => 'β',
+        0xE3 => 'ε',
+        0xE4 => 'µ',
+        0xE5 => 'σ',
+        0xE6 => 'ρ',
+        0xF2 => 'θ',
+        0xF3 => '∞',
+        0xF4 => 'Ω',
+        0xF6 => 'Σ',
+        0xF7 => 'π',
+        0x20..=0x7D => byte as char,
+        _ => '?',
+    }
+}
+
+/// Decodes the `#` terminated display contents.
+pub fn decode_display(input: &[u8]) -> Result<String, Error> {
+    let (input, display) = take_until("#")(input)?;
+    let (_, _) = tag("#")(input)?;
+    Ok(display.iter().copied().map(decode_char).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Digit, HandboxKey};
+
+    #[test]
+    fn test_press_request() {
+        assert!(Digit::new(10).is_none());
+        let seven = HandboxKey::Digit(Digit::new(7).unwrap());
+        assert_eq!(seven.press_request(), b":EK55#");
+        assert_eq!(HandboxKey::GoTo.press_request(), b":EK24#");
+    }
+}
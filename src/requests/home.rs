@@ -0,0 +1,28 @@
+use nom::{branch::alt, bytes::complete::tag, combinator::value};
+
+use crate::error::Error;
+
+pub const GET_HOME_STATUS: &[u8] = b":h?#";
+pub const GET_HOME_STATUS_SIZE: usize = 1;
+
+#[derive(Clone)]
+pub enum HomeStatus {
+    /// 0 is returned if the home search failed,
+    Failed,
+    /// 1 is returned if the home position was found,
+    Found,
+    /// 2 is returned if the home search is still in progress.
+    InProgress,
+}
+
+impl HomeStatus {
+    pub fn parse(input: &[u8]) -> Result<Self, Error> {
+        let (_, status) = alt((
+            value(HomeStatus::Failed, tag(b"0")),
+            value(HomeStatus::Found, tag(b"1")),
+            value(HomeStatus::InProgress, tag(b"2")),
+        ))(input)?;
+
+        Ok(status)
+    }
+}
@@ -0,0 +1,201 @@
+use core::str;
+use std::fmt::{Display, Write};
+
+use nom::bytes::complete::{tag, take, take_until};
+
+use crate::error::Error;
+
+pub const GET_OBJECT_INFO: &[u8] = b":LI#";
+
+/// Represents `NNNN` from `0000-9999`
+pub struct ObjectNumber(u16);
+
+impl ObjectNumber {
+    pub const fn new(val: u16) -> Option<Self> {
+        if val > 9999 {
+            return None;
+        }
+        Some(ObjectNumber(val))
+    }
+}
+
+impl Display for ObjectNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+/// Deep sky catalog used by [crate::Client::select_deep_sky].
+pub enum DeepSkyCatalog {
+    /// CNGC on the LX200, NGC on Autostar and LX200GPS
+    Ngc,
+    Ic,
+    Ugc,
+}
+
+impl Display for DeepSkyCatalog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeepSkyCatalog::Ngc => f.write_char('0'),
+            DeepSkyCatalog::Ic => f.write_char('1'),
+            DeepSkyCatalog::Ugc => f.write_char('2'),
+        }
+    }
+}
+
+/// Star catalog used by [crate::Client::select_star].
+pub enum StarCatalog {
+    Star,
+    Sao,
+    Gcvs,
+    Hd,
+    Hr,
+}
+
+impl Display for StarCatalog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StarCatalog::Star => f.write_char('0'),
+            StarCatalog::Sao => f.write_char('1'),
+            StarCatalog::Gcvs => f.write_char('2'),
+            StarCatalog::Hd => f.write_char('3'),
+            StarCatalog::Hr => f.write_char('4'),
+        }
+    }
+}
+
+/// Observing quality of an object as rated by the library.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ObjectQuality {
+    Superior,
+    Excellent,
+    VeryGood,
+    Good,
+    Fair,
+    Poor,
+    VeryPoor,
+}
+
+impl ObjectQuality {
+    /// Reads the two letter quality code, e.g. `VG`.
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "SU" => Some(ObjectQuality::Superior),
+            "EX" => Some(ObjectQuality::Excellent),
+            "VG" => Some(ObjectQuality::VeryGood),
+            "GD" => Some(ObjectQuality::Good),
+            "FR" => Some(ObjectQuality::Fair),
+            "PR" => Some(ObjectQuality::Poor),
+            "VP" => Some(ObjectQuality::VeryPoor),
+            _ => None,
+        }
+    }
+
+    pub fn parse(input: &[u8]) -> Result<Self, Error> {
+        let (input, code) = take(2usize)(input)?;
+        let (_, _) = tag("#")(input)?;
+        str::from_utf8(code)
+            .ok()
+            .and_then(Self::from_code)
+            .ok_or_else(|| Error::Format(format!("unknown quality {:?}", code)))
+    }
+}
+
+impl Display for ObjectQuality {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ObjectQuality::Superior => write!(f, "SU"),
+            ObjectQuality::Excellent => write!(f, "EX"),
+            ObjectQuality::VeryGood => write!(f, "VG"),
+            ObjectQuality::Good => write!(f, "GD"),
+            ObjectQuality::Fair => write!(f, "FR"),
+            ObjectQuality::Poor => write!(f, "PR"),
+            ObjectQuality::VeryPoor => write!(f, "VP"),
+        }
+    }
+}
+
+/// Information about the current object, e.g. `M31 EX GAL MAG 3.5 SZ178.0'#`.
+///
+/// The string is free-form and not every object reports every field, so anything missing is left as [None].
+#[derive(Debug, PartialEq)]
+pub struct ObjectInfo {
+    pub name: String,
+    pub object_type: Option<String>,
+    pub quality: Option<ObjectQuality>,
+    pub magnitude: Option<f32>,
+    /// Size in arc minutes.
+    pub size: Option<f32>,
+}
+
+impl ObjectInfo {
+    pub fn parse(input: &[u8]) -> Result<Self, Error> {
+        let (input, info) = take_until("#")(input)?;
+        let (_, _) = tag("#")(input)?;
+
+        let info = String::from_utf8_lossy(info);
+        let mut tokens = info.split_whitespace();
+        let name = tokens
+            .next()
+            .ok_or_else(|| Error::Format("missing object name".to_string()))?
+            .to_string();
+
+        let mut object_type = Vec::new();
+        let mut quality = None;
+        let mut magnitude = None;
+        let mut size = None;
+        while let Some(token) = tokens.next() {
+            if let Some(value) = token.strip_prefix("MAG") {
+                let value = match value {
+                    "" => tokens.next().unwrap_or_default(),
+                    value => value,
+                };
+                magnitude = Some(parse_number(value)?);
+            } else if let Some(value) = token.strip_prefix("SZ") {
+                let value = match value {
+                    "" => tokens.next().unwrap_or_default(),
+                    value => value,
+                };
+                size = Some(parse_number(value.trim_end_matches('\''))?);
+            } else if let Some(q) = ObjectQuality::from_code(token) {
+                quality = Some(q);
+            } else {
+                object_type.push(token);
+            }
+        }
+
+        Ok(ObjectInfo {
+            name,
+            object_type: (!object_type.is_empty()).then(|| object_type.join(" ")),
+            quality,
+            magnitude,
+            size,
+        })
+    }
+}
+
+fn parse_number(value: &str) -> Result<f32, Error> {
+    value
+        .parse()
+        .map_err(|_| Error::Format(format!("invalid number {:?}", value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ObjectInfo, ObjectQuality};
+
+    #[test]
+    fn test_parse_object_info() {
+        let info = ObjectInfo::parse(b"M31 EX GAL MAG 3.5 SZ178.0'#").unwrap();
+        assert_eq!(
+            info,
+            ObjectInfo {
+                name: "M31".to_string(),
+                object_type: Some("GAL".to_string()),
+                quality: Some(ObjectQuality::Excellent),
+                magnitude: Some(3.5),
+                size: Some(178.0),
+            }
+        );
+    }
+}
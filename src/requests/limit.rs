@@ -0,0 +1,74 @@
+use core::str;
+use std::fmt::Display;
+
+use nom::bytes::complete::{tag, take};
+
+use crate::{error::Error, formats::degs::SignedDegrees};
+
+pub const GET_HIGHER_LIMIT: &[u8] = b":Gh#";
+pub const GET_HIGHER_LIMIT_SIZE: usize = b"sDD\xDF#".len();
+pub const GET_LOWER_LIMIT: &[u8] = b":Go#";
+pub const GET_LOWER_LIMIT_SIZE: usize = b"DD\xDF#".len();
+
+/// The 'higher' limit, which is the minimum elevation `sDD` the telescope will slew to.
+/// It can be below the horizon.
+pub struct HorizonLimit {
+    pub degrees: SignedDegrees,
+}
+
+impl HorizonLimit {
+    pub fn parse(input: &[u8]) -> Result<Self, Error> {
+        let (input, degrees) = SignedDegrees::from_bytes(input)?;
+        let (input, _) = tag(b"\xDF")(input)?;
+        let (_, _) = tag("#")(input)?;
+        Ok(HorizonLimit { degrees })
+    }
+
+    pub fn set_request(&self) -> Vec<u8> {
+        format!(":Sh {}#", self.degrees).into_bytes()
+    }
+}
+
+/// The 'lower' limit, which despite its name is the maximum (overhead) elevation `DD` in degrees from `00-90`
+/// the telescope will slew to.
+#[derive(Debug, PartialEq)]
+pub struct ElevationLimit(u8);
+
+impl ElevationLimit {
+    pub const fn new(val: u8) -> Option<Self> {
+        if val > 90 {
+            return None;
+        }
+        Some(ElevationLimit(val))
+    }
+
+    pub fn parse(input: &[u8]) -> Result<Self, Error> {
+        let (input, degrees) = take(2usize)(input)?;
+        let (input, _) = tag(b"\xDF")(input)?;
+        let (_, _) = tag("#")(input)?;
+        str::from_utf8(degrees)
+            .ok()
+            .and_then(|degrees| degrees.parse::<u8>().ok())
+            .and_then(Self::new)
+            .ok_or_else(|| Error::Format(format!("invalid elevation limit {:?}", degrees)))
+    }
+}
+
+impl Display for ElevationLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HorizonLimit;
+
+    #[test]
+    fn test_parse_signed_horizon_limit() {
+        let limit = HorizonLimit::parse(b"+10\xDF#").unwrap();
+        assert_eq!(limit.degrees, 10);
+        let limit = HorizonLimit::parse(b"-05\xDF#").unwrap();
+        assert_eq!(limit.set_request(), b":Sh -05#");
+    }
+}
@@ -1,22 +1,45 @@
 use std::fmt::{Display, Write};
 
-use nom::{branch::alt, bytes::complete::tag, combinator::value};
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_until},
+    combinator::value,
+};
 
 use crate::error::Error;
 
 pub mod alt;
 pub mod asc;
 pub mod azim;
+pub mod backlash;
 pub mod cal;
+pub mod clock;
 pub mod decl;
+pub mod distance;
+pub mod find;
+pub mod firmware;
+pub mod focus;
+pub mod gps;
+pub mod handbox;
+pub mod home;
 pub mod latlng;
+pub mod library;
+pub mod limit;
+pub mod pointing;
+pub mod reticle;
 pub mod site;
 pub mod time;
+pub mod tracking;
 
 pub const GET_ALIGNMENT_STATUS: &[u8] = &[6];
 pub const GET_ALIGNMENT_STATUS_SIZE: usize = 1;
 
-pub const SYNC: &[u8] = b":GM#";
+pub const SYNC: &[u8] = b":CM#";
+
+pub const GET_STATUS: &[u8] = b":GU#";
+
+/// Longest pulse accepted by the pulse guide commands.
+pub const MAX_PULSE_GUIDE_MILLIS: u128 = 9999;
 
 #[derive(Clone)]
 pub enum AlignmentStatus {
@@ -37,6 +60,56 @@ impl AlignmentStatus {
 
         Ok(status)
     }
+
+    /// German polar can only be chosen from the handset, so it yields [Error::Unsupported].
+    pub fn set_request(&self) -> Result<&'static [u8], Error> {
+        match self {
+            AlignmentStatus::AltitudeAzmuth => Ok(b":AA#"),
+            AlignmentStatus::Land => Ok(b":AL#"),
+            AlignmentStatus::Polar => Ok(b":AP#"),
+            AlignmentStatus::GermanPolar => Err(Error::Unsupported),
+        }
+    }
+}
+
+/// Coordinate format used by the telescope, toggled with `:U#`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Precision {
+    /// `HH:MM.T` and `sDD*MM`
+    Low,
+    /// `HH:MM:SS` and `sDD*MM'SS`
+    High,
+}
+
+impl Precision {
+    pub fn toggle(&self) -> Self {
+        match self {
+            Precision::Low => Precision::High,
+            Precision::High => Precision::Low,
+        }
+    }
+}
+
+/// Reply to a sync naming what the telescope coordinates were matched to.
+#[derive(Debug, PartialEq)]
+pub enum SyncResult {
+    /// The coordinates of the named object, e.g. `M31 EX GAL MAG 3.5 SZ178.0'`.
+    Object(String),
+    /// The object coordinates that were set by hand, reported as `Coordinates     matched.`.
+    Coordinates,
+}
+
+impl SyncResult {
+    pub fn parse(input: &[u8]) -> Result<Self, Error> {
+        let (input, reply) = take_until("#")(input)?;
+        let (_, _) = tag("#")(input)?;
+        let reply = String::from_utf8_lossy(reply);
+        let reply = reply.trim();
+        if reply.starts_with("Coordinates") {
+            return Ok(SyncResult::Coordinates);
+        }
+        Ok(SyncResult::Object(reply.to_string()))
+    }
 }
 
 pub enum Direction {
@@ -57,25 +130,35 @@ impl Display for Direction {
     }
 }
 
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum SlewStatus {
     /// 0 is returned if the telescope can complete the slew,
     CanComplete,
-    /// 1 is returned if the object is below the horizon,
-    BelowHorizon,
-    /// 2 is returned if the object is below the 'higher' limit.
-    BelowLimit,
+    /// 1 is returned if the object is below the horizon, along with a message,
+    BelowHorizon(String),
+    /// 2 is returned if the object is below the 'higher' limit, along with a message.
+    BelowLimit(String),
 }
 
 impl SlewStatus {
+    /// Whether the status is followed by a `#` terminated message.
+    pub fn has_message(status: u8) -> bool {
+        matches!(status, b'1' | b'2')
+    }
+
     pub fn parse(input: &[u8]) -> Result<Self, Error> {
-        let (_, status) = alt((
-            value(SlewStatus::CanComplete, tag(b"0")),
-            value(SlewStatus::BelowHorizon, tag(b"1")),
-            value(SlewStatus::BelowLimit, tag(b"2")),
-        ))(input)?;
+        let (input, status) = alt((tag(b"0"), tag(b"1"), tag(b"2")))(input)?;
+        if status == b"0" {
+            return Ok(SlewStatus::CanComplete);
+        }
 
-        Ok(status)
+        let (input, message) = take_until("#")(input)?;
+        let (_, _) = tag("#")(input)?;
+        let message = String::from_utf8_lossy(message).trim().to_string();
+        match status {
+            b"1" => Ok(SlewStatus::BelowHorizon(message)),
+            _ => Ok(SlewStatus::BelowLimit(message)),
+        }
     }
 }
 
@@ -96,3 +179,40 @@ impl Display for MotionRate {
         }
     }
 }
+
+/// Fastest custom axis rate in degrees per second.
+pub const MAX_AXIS_RATE: f64 = 8.0;
+
+/// Telescope axis, the azimuth and altitude axes in alt-az mode.
+pub enum Axis {
+    RightAscension,
+    Declination,
+}
+
+impl Display for Axis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Axis::RightAscension => f.write_char('A'),
+            Axis::Declination => f.write_char('E'),
+        }
+    }
+}
+
+/// Represents the maximum slew rate `N` in degrees per second from `2-8`
+#[derive(Debug, PartialEq)]
+pub struct MaxSlewRate(u8);
+
+impl MaxSlewRate {
+    pub const fn new(val: u8) -> Option<Self> {
+        if val < 2 || val > 8 {
+            return None;
+        }
+        Some(MaxSlewRate(val))
+    }
+}
+
+impl Display for MaxSlewRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
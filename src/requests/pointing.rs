@@ -0,0 +1,47 @@
+use nom::{branch::alt, bytes::complete::tag, combinator::value};
+
+use crate::error::Error;
+
+pub const TOGGLE_POINTING_PRECISION: &[u8] = b":P#";
+
+/// Pointing mode of the telescope, toggled with `:P#`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PointingPrecision {
+    /// GOTOs slew straight to the object.
+    Low,
+    /// GOTOs first slew to a nearby bright star to be centered before moving to the object.
+    High,
+}
+
+impl PointingPrecision {
+    /// Size of the reply to `:P#` given its first byte, as the reply is not `#` terminated.
+    pub fn response_size(first: u8) -> Result<usize, Error> {
+        match first {
+            b'H' => Ok(b"HIGH PRECISION".len()),
+            b'L' => Ok(b"LOW PRECISION".len()),
+            b => Err(Error::Format(format!(
+                "unknown pointing precision {:?}",
+                b as char
+            ))),
+        }
+    }
+
+    pub fn parse(input: &[u8]) -> Result<Self, Error> {
+        let (_, precision) = alt((
+            value(PointingPrecision::High, tag(b"HIGH PRECISION")),
+            value(PointingPrecision::Low, tag(b"LOW PRECISION")),
+        ))(input)?;
+
+        Ok(precision)
+    }
+}
+
+/// Where the telescope stopped once a slew finished.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SlewArrival {
+    /// The slew reached the object.
+    Object,
+    /// High precision pointing stopped the slew at a nearby bright star,
+    /// which waits to be centered before continuing to the object.
+    IntermediateStar,
+}
@@ -0,0 +1,36 @@
+use std::fmt::{Display, Write};
+
+/// Step of the illuminated reticle brightness.
+pub enum BrightnessStep {
+    Increase,
+    Decrease,
+}
+
+impl Display for BrightnessStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BrightnessStep::Increase => f.write_char('+'),
+            BrightnessStep::Decrease => f.write_char('-'),
+        }
+    }
+}
+
+/// Flash rate of the illuminated reticle.
+pub enum FlashMode {
+    /// Does not flash.
+    Steady,
+    One,
+    Two,
+    Three,
+}
+
+impl Display for FlashMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FlashMode::Steady => f.write_char('0'),
+            FlashMode::One => f.write_char('1'),
+            FlashMode::Two => f.write_char('2'),
+            FlashMode::Three => f.write_char('3'),
+        }
+    }
+}
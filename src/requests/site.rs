@@ -1,5 +1,17 @@
 use std::fmt::Display;
 
+use nom::bytes::complete::{tag, take_until};
+
+use crate::{
+    error::Error,
+    formats::hrs::SignedHours,
+    requests::latlng::{Latitude, Longitude},
+};
+
+/// Longest site name accepted by Autostar and LX200GPS telescopes.
+/// The classic LX200 only keeps the first three characters.
+pub const SITE_NAME_MAX_SIZE: usize = 15;
+
 pub enum Site {
     One,
     Two,
@@ -7,6 +19,42 @@ pub enum Site {
     Four,
 }
 
+impl Site {
+    pub fn get_name_request(&self) -> &'static [u8] {
+        match self {
+            Site::One => b":GM#",
+            Site::Two => b":GN#",
+            Site::Three => b":GO#",
+            Site::Four => b":GP#",
+        }
+    }
+
+    pub fn set_name_request(&self, name: &str) -> Result<Vec<u8>, Error> {
+        if name.len() > SITE_NAME_MAX_SIZE {
+            return Err(Error::Format(format!(
+                "site name is longer than {} characters",
+                SITE_NAME_MAX_SIZE
+            )));
+        }
+        if !name.is_ascii() || name.contains('#') {
+            return Err(Error::Format(format!("invalid site name {:?}", name)));
+        }
+        let command = match self {
+            Site::One => 'M',
+            Site::Two => 'N',
+            Site::Three => 'O',
+            Site::Four => 'P',
+        };
+        Ok(format!(":S{}{}#", command, name).into_bytes())
+    }
+
+    pub fn parse_name(input: &[u8]) -> Result<String, Error> {
+        let (input, name) = take_until("#")(input)?;
+        let (_, _) = tag("#")(input)?;
+        Ok(String::from_utf8_lossy(name).trim().to_string())
+    }
+}
+
 impl Display for Site {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -17,3 +65,23 @@ impl Display for Site {
         }
     }
 }
+
+/// Snapshot of everything stored for a site.
+pub struct SiteInfo {
+    pub name: String,
+    pub latitude: Latitude,
+    pub longitude: Longitude,
+    pub gmt_offset: SignedHours,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Site;
+
+    #[test]
+    fn test_set_name_request() {
+        assert_eq!(Site::One.set_name_request("Home").unwrap(), b":SMHome#");
+        assert_eq!(Site::Four.set_name_request("Dark").unwrap(), b":SPDark#");
+        assert!(Site::One.set_name_request("A#B").is_err());
+    }
+}
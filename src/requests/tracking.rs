@@ -0,0 +1,56 @@
+use core::str;
+use std::fmt::Display;
+
+use nom::bytes::complete::{tag, take};
+
+use crate::error::Error;
+
+pub const GET_TRACKING_RATE: &[u8] = b":GT#";
+pub const GET_TRACKING_RATE_SIZE: usize = b"TT.T#".len();
+
+/// Represents the tracking frequency `TT.T` in hertz from `56.4-60.1`
+#[derive(Debug, PartialEq)]
+pub struct TrackingRate(f32);
+
+impl TrackingRate {
+    /// Rate of the quartz sidereal clock.
+    pub const SIDEREAL: TrackingRate = TrackingRate(60.1);
+
+    #[inline]
+    fn is_invalid(val: f32) -> bool {
+        !(56.4..=60.1).contains(&val)
+    }
+
+    pub fn new(val: f32) -> Option<Self> {
+        if Self::is_invalid(val) {
+            return None;
+        }
+        Some(TrackingRate(val))
+    }
+
+    pub fn parse(input: &[u8]) -> Result<Self, Error> {
+        let (input, rate) = take(4usize)(input)?;
+        let (_, _) = tag("#")(input)?;
+        let rate = str::from_utf8(rate)
+            .ok()
+            .and_then(|rate| rate.parse::<f32>().ok())
+            .ok_or_else(|| Error::Format(format!("invalid tracking rate {:?}", rate)))?;
+        if Self::is_invalid(rate) {
+            return Err(Error::Format(format!(
+                "tracking rate {} out of range",
+                rate
+            )));
+        }
+        Ok(TrackingRate(rate))
+    }
+
+    pub fn hertz(&self) -> f32 {
+        self.0
+    }
+}
+
+impl Display for TrackingRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04.1}", self.0)
+    }
+}